@@ -38,6 +38,12 @@ export interface I18nexusConfig {
    * 예: "getServerTranslation", "getServerT" 등
    */
   serverTranslationFunction?: string;
+  /**
+   * t-wrapper "lazy" 전략의 지연 번역 마커 함수명 (t-wrapper의 lazy_translation_function)
+   * extractor가 이 이름의 호출도 번역 키로 추출합니다.
+   * @default "tKey"
+   */
+  lazyTranslationFunction?: string;
   googleSheets?: {
     spreadsheetId: string;
     credentialsPath: string;
@@ -17,7 +17,11 @@ export const CONSOLE_MESSAGES = Object.freeze({
 // 문자열 상수
 export const STRING_CONSTANTS = Object.freeze({
   TRANSLATION_FUNCTION: "t",
+  // 컴포넌트 밖 문자열을 감싸는 지연 번역 마커의 기본 이름 (t-wrapper의 "lazy" 전략)
+  // lazyTranslationFunction 설정으로 바꿀 수 있음
+  LAZY_TRANSLATION_FUNCTION: "tKey",
   DEFAULT_VALUE: "defaultValue",
+  COUNT_OPTION: "count",
   CSV_HEADER: "Key,English,Korean",
   CSV_SEPARATOR: ",",
   CSV_NEWLINE: "\n",
@@ -34,6 +38,12 @@ export const STRING_CONSTANTS = Object.freeze({
   EMPTY_STRING: "",
 } as const);
 
+// 복수형 키 접미사 (i18next count 옵션)
+export const PLURAL_SUFFIXES = Object.freeze({
+  ONE: "_one",
+  OTHER: "_other",
+} as const);
+
 // 파일 확장자
 export const FILE_EXTENSIONS = Object.freeze({
   JSON: ".json",
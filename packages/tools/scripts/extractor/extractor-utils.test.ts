@@ -25,6 +25,16 @@ describe("extractor-utils", () => {
       expect(isTFunction(callee)).toBe(true);
     });
 
+    it("tKey() 지연 번역 마커를 감지해야 함", () => {
+      const callee = t.identifier("tKey");
+      expect(isTFunction(callee)).toBe(true);
+    });
+
+    it("설정한 지연 번역 마커 이름을 사용해야 함", () => {
+      expect(isTFunction(t.identifier("msg"), "msg")).toBe(true);
+      expect(isTFunction(t.identifier("tKey"), "msg")).toBe(false);
+    });
+
     it("다른 함수 호출은 false를 반환해야 함", () => {
       const callee = t.identifier("translate");
       expect(isTFunction(callee)).toBe(false);
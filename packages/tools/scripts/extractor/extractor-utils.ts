@@ -8,15 +8,22 @@ import { STRING_CONSTANTS, CSV_CONSTANTS } from "./constants";
 
 /**
  * t() 함수 호출인지 확인
+ * lazyTranslationFunction: t-wrapper가 쓰는 지연 번역 마커 이름 (기본 tKey)
  */
 export function isTFunction(
-  callee: t.Expression | t.V8IntrinsicIdentifier
+  callee: t.Expression | t.V8IntrinsicIdentifier,
+  lazyTranslationFunction: string = STRING_CONSTANTS.LAZY_TRANSLATION_FUNCTION
 ): boolean {
   // t() 직접 호출
   if (t.isIdentifier(callee, { name: STRING_CONSTANTS.TRANSLATION_FUNCTION })) {
     return true;
   }
 
+  // tKey() 지연 번역 마커 (렌더링 시점에 t()로 번역되는 키)
+  if (t.isIdentifier(callee, { name: lazyTranslationFunction })) {
+    return true;
+  }
+
   // useTranslation().t 형태의 호출
   if (
     t.isMemberExpression(callee) &&
@@ -53,6 +60,22 @@ export function getDefaultValue(args: t.Expression[]): string | undefined {
   return undefined;
 }
 
+/**
+ * t() 옵션 객체에 count가 있는지 확인 (복수형 키)
+ * 예: t("{{count}}개의 알림", { count }) / t("...", { count: items.length })
+ */
+export function hasCountOption(args: t.Expression[]): boolean {
+  if (args.length > 1 && t.isObjectExpression(args[1])) {
+    return args[1].properties.some(
+      (prop) =>
+        t.isObjectProperty(prop) &&
+        t.isIdentifier(prop.key, { name: STRING_CONSTANTS.COUNT_OPTION })
+    );
+  }
+
+  return false;
+}
+
 /**
  * CSV 값 이스케이프 처리
  */
@@ -20,6 +20,7 @@ import {
 } from "./key-extractor";
 import {
   generateOutputData,
+  getPluralKeys,
   writeOutputFile,
   writeOutputFileWithNamespace,
   generateNamespaceIndexFile,
@@ -41,6 +42,7 @@ export interface ExtractorConfig {
   outputDir?: string;
   namespace?: string; // 레거시: 직접 지정 (namespacing.enabled=false일 때만 사용)
   translationImportSource?: string; // e.g., "i18nexus", "react-i18next"
+  lazyTranslationFunction?: string; // t-wrapper 지연 번역 마커 이름 (기본 "tKey")
   includeLineNumbers?: boolean;
   includeFilePaths?: boolean;
   sortKeys?: boolean;
@@ -67,6 +69,7 @@ const DEFAULT_CONFIG: Required<ExtractorConfig> = {
   languages: [...COMMON_DEFAULTS.languages], // 기본 언어
   fallbackNamespace: COMMON_DEFAULTS.fallbackNamespace,
   translationImportSource: COMMON_DEFAULTS.translationImportSource,
+  lazyTranslationFunction: STRING_CONSTANTS.LAZY_TRANSLATION_FUNCTION,
   force: false, // 기본값: 기존 번역 유지
   namespaceStrategy: "full", // 기본값: full
   namespacing: {
@@ -93,6 +96,8 @@ export class TranslationExtractor {
     const namespacingConfig = config.namespacing || projectConfig.namespacing;
     const translationImportSource =
       config.translationImportSource || projectConfig.translationImportSource;
+    const lazyTranslationFunction =
+      config.lazyTranslationFunction || projectConfig.lazyTranslationFunction;
 
     this.config = {
       ...DEFAULT_CONFIG,
@@ -101,6 +106,8 @@ export class TranslationExtractor {
       skipValidation: config.skipValidation || false,
       translationImportSource:
         translationImportSource || DEFAULT_CONFIG.translationImportSource,
+      lazyTranslationFunction:
+        lazyTranslationFunction || DEFAULT_CONFIG.lazyTranslationFunction,
     };
   }
 
@@ -159,6 +166,7 @@ export class TranslationExtractor {
           const extractedKey = extractTranslationKey(path, filePath, {
             includeFilePaths: this.config.includeFilePaths,
             includeLineNumbers: this.config.includeLineNumbers,
+            lazyTranslationFunction: this.config.lazyTranslationFunction,
           });
           if (extractedKey) {
             this.addExtractedKey(extractedKey, namespace);
@@ -178,6 +186,9 @@ export class TranslationExtractor {
       const existingKey = this.extractedKeys.get(key);
       if (!existingKey) {
         this.extractedKeys.set(key, extractedKey);
+      } else if (extractedKey.plural) {
+        // 같은 키가 한 곳에서라도 count와 함께 쓰이면 복수형으로 유지
+        existingKey.plural = true;
       }
       return;
     }
@@ -207,7 +218,11 @@ export class TranslationExtractor {
       this.namespaceKeys.set(targetNamespace, new Map());
     }
     const namespaceMap = this.namespaceKeys.get(targetNamespace)!;
-    namespaceMap.set(key, extractedKey);
+    const existingKey = namespaceMap.get(key);
+    namespaceMap.set(
+      key,
+      existingKey?.plural ? { ...extractedKey, plural: true } : extractedKey
+    );
   }
 
   /**
@@ -274,6 +289,7 @@ export class TranslationExtractor {
           writeOutputFileWithNamespace(outputData, {
             outputFormat: this.config.outputFormat,
             languages: this.config.languages!,
+            pluralKeys: getPluralKeys(keys),
             outputDir: this.config.outputDir,
             namespace,
             force: this.config.force,
@@ -320,6 +336,7 @@ export class TranslationExtractor {
         writeOutputFile(outputData, {
           outputFormat: this.config.outputFormat,
           languages: this.config.languages,
+          pluralKeys: getPluralKeys(keys),
           outputDir: this.config.outputDir,
           outputFile: this.config.outputFile,
           force: this.config.force,
@@ -39,6 +39,21 @@ describe("key-extractor", () => {
       expect(extracted).toBeNull();
     });
 
+    it("설정한 지연 번역 마커 호출에서 키를 추출해야 함", () => {
+      const code = `const LABELS = { save: msg("저장") };`;
+      const ast = parse(code, { sourceType: "module", plugins: ["typescript", "jsx"] });
+      let extracted: ExtractedKey | null = null;
+      traverse(ast, {
+        CallExpression(path) {
+          extracted = extractTranslationKey(path, "test.ts", {
+            lazyTranslationFunction: "msg",
+          });
+        },
+      });
+      expect(extracted).not.toBeNull();
+      expect(extracted?.key).toBe("저장");
+    });
+
     it("첫 번째 인수가 문자열이 아니면 null을 반환해야 함", () => {
       const code = `t(variable);`;
       const ast = parse(code, { sourceType: "module", plugins: ["typescript", "jsx"] });
@@ -81,6 +96,28 @@ describe("key-extractor", () => {
       expect(key.key).toBe("hello.world");
       expect(key.defaultValue).toBe("기본값");
     });
+
+    it("count 옵션이 있으면 복수형 키로 표시해야 함", () => {
+      const callExpr = t.callExpression(t.identifier("t"), [
+        t.stringLiteral("{{count}}개의 알림"),
+        t.objectExpression([
+          t.objectProperty(t.identifier("count"), t.identifier("count"), false, true),
+        ]),
+      ]);
+      const key = createExtractedKey("{{count}}개의 알림", callExpr, "src/component.tsx");
+      expect(key.plural).toBe(true);
+    });
+
+    it("count 옵션이 없으면 복수형 키로 표시하지 않아야 함", () => {
+      const callExpr = t.callExpression(t.identifier("t"), [
+        t.stringLiteral("{{name}}님"),
+        t.objectExpression([
+          t.objectProperty(t.identifier("name"), t.identifier("name"), false, true),
+        ]),
+      ]);
+      const key = createExtractedKey("{{name}}님", callExpr, "src/component.tsx");
+      expect(key.plural).toBeUndefined();
+    });
   });
 });
 
@@ -5,11 +5,13 @@
 import { NodePath } from "@babel/traverse";
 import * as t from "@babel/types";
 import * as pathLib from "path";
-import { isTFunction, getDefaultValue } from "./extractor-utils";
+import { isTFunction, getDefaultValue, hasCountOption } from "./extractor-utils";
 
 export interface ExtractedKey {
   key: string;
   defaultValue?: string;
+  /** t() 옵션에 count가 있는 복수형 키 */
+  plural?: boolean;
   filePath?: string;
   lineNumber?: number;
   columnNumber?: number;
@@ -18,6 +20,8 @@ export interface ExtractedKey {
 export interface ExtractorConfig {
   includeFilePaths?: boolean;
   includeLineNumbers?: boolean;
+  /** 지연 번역 마커 함수 이름 (기본 tKey) */
+  lazyTranslationFunction?: string;
 }
 
 /**
@@ -31,7 +35,7 @@ export function extractTranslationKey(
   const { node } = path;
 
   // t() 함수 호출 감지
-  if (!isTFunction(node.callee)) {
+  if (!isTFunction(node.callee, config?.lazyTranslationFunction)) {
     return null;
   }
 
@@ -60,17 +64,20 @@ export function createExtractedKey(
   config?: ExtractorConfig
 ): ExtractedKey {
   const loc = node.loc;
+  const args = node.arguments.filter(
+    (arg): arg is t.Expression =>
+      !t.isArgumentPlaceholder(arg) && !t.isSpreadElement(arg)
+  );
 
   const extractedKey: ExtractedKey = {
     key,
-    defaultValue: getDefaultValue(
-      node.arguments.filter(
-        (arg): arg is t.Expression =>
-          !t.isArgumentPlaceholder(arg) && !t.isSpreadElement(arg)
-      )
-    ),
+    defaultValue: getDefaultValue(args),
   };
 
+  if (hasCountOption(args)) {
+    extractedKey.plural = true;
+  }
+
   if (config?.includeFilePaths) {
     extractedKey.filePath = pathLib.relative(process.cwd(), filePath);
   }
@@ -7,6 +7,7 @@ import * as fs from "fs";
 import * as path from "path";
 import {
   generateOutputData,
+  getPluralKeys,
   generateGoogleSheetsCSV,
   generateIndexFile,
   generateNamespaceIndexFile,
@@ -70,6 +71,16 @@ describe("output-generator", () => {
     });
   });
 
+  describe("getPluralKeys", () => {
+    it("count 옵션과 함께 쓰인 키만 반환해야 함", () => {
+      const keys: ExtractedKey[] = [
+        { key: "{{count}}개의 알림", plural: true },
+        { key: "저장" },
+      ];
+      expect(getPluralKeys(keys)).toEqual(["{{count}}개의 알림"]);
+    });
+  });
+
   describe("generateGoogleSheetsCSV", () => {
     it("CSV 형식으로 변환해야 함", () => {
       const keys: ExtractedKey[] = [
@@ -354,6 +365,74 @@ describe("output-generator", () => {
       expect(koData["welcome.title"]).toBe("환영합니다");
     });
 
+    it("복수형 키는 대상 언어에 _one/_other 항목을 생성해야 함", () => {
+      const data = {
+        "{{count}}개의 알림": "{{count}}개의 알림",
+        "button.save": "저장",
+      };
+
+      writeOutputFileWithNamespace(data, {
+        outputFormat: "json",
+        languages: ["en", "ko"],
+        outputDir: tempDir,
+        namespace: "common",
+        pluralKeys: ["{{count}}개의 알림"],
+        dryRun: false,
+      });
+
+      const enData = readJsonFile(path.join(tempDir, "common", "en.json"));
+      const koData = readJsonFile(path.join(tempDir, "common", "ko.json"));
+
+      expect(enData).toEqual({
+        "{{count}}개의 알림_one": "",
+        "{{count}}개의 알림_other": "",
+        "button.save": "",
+      });
+      expect(koData).toEqual({
+        "{{count}}개의 알림": "{{count}}개의 알림",
+        "button.save": "저장",
+      });
+    });
+
+    it("복수형이 된 기존 키는 단수 항목을 _one/_other로 옮겨야 함", () => {
+      const namespaceDir = path.join(tempDir, "common");
+      fs.mkdirSync(namespaceDir, { recursive: true });
+      fs.writeFileSync(
+        path.join(namespaceDir, "en.json"),
+        JSON.stringify(
+          {
+            "{{count}}개의 알림": "{{count}} notifications",
+            "button.save": "Save",
+          },
+          null,
+          2,
+        ),
+      );
+
+      writeOutputFileWithNamespace(
+        {
+          "{{count}}개의 알림": "{{count}}개의 알림",
+          "button.save": "저장",
+        },
+        {
+          outputFormat: "json",
+          languages: ["en"],
+          outputDir: tempDir,
+          namespace: "common",
+          pluralKeys: ["{{count}}개의 알림"],
+          dryRun: false,
+        },
+      );
+
+      const enData = readJsonFile(path.join(namespaceDir, "en.json"));
+      expect(enData).toEqual({
+        "button.save": "Save",
+        "{{count}}개의 알림_one": "{{count}} notifications",
+        "{{count}}개의 알림_other": "{{count}} notifications",
+      });
+      expect(enData).not.toHaveProperty(["{{count}}개의 알림"]);
+    });
+
     it("force 모드에서는 기존 번역을 덮어써야 함", () => {
       const namespaceDir = path.join(tempDir, "dashboard");
       fs.mkdirSync(namespaceDir, { recursive: true });
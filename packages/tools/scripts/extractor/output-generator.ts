@@ -11,6 +11,7 @@ import {
   STRING_CONSTANTS,
   CSV_CONSTANTS,
   FILE_EXTENSIONS,
+  PLURAL_SUFFIXES,
 } from "./constants";
 
 export interface OutputConfig {
@@ -21,6 +22,8 @@ export interface OutputConfig {
   outputFile?: string;
   force?: boolean;
   dryRun?: boolean;
+  /** count 옵션과 함께 쓰인 키 (대상 언어에 _one/_other 항목 생성) */
+  pluralKeys?: string[];
 }
 
 /**
@@ -49,6 +52,61 @@ export function generateOutputData(
   return result;
 }
 
+/**
+ * 복수형 키 목록 (t() 옵션에 count가 있는 키)
+ */
+export function getPluralKeys(keys: ExtractedKey[]): string[] {
+  return keys.filter(({ plural }) => plural).map(({ key }) => key);
+}
+
+/**
+ * 새 키의 언어별 초기 항목 생성
+ * - 한국어: 키를 그대로 또는 defaultValue 사용 (복수형 구분이 없어 키 하나만 유지)
+ * - 기타 언어: 빈 문자열, 복수형 키는 key_one / key_other로 생성
+ */
+function seedTranslations(
+  key: string,
+  value: string,
+  lang: string,
+  pluralKeys: string[] = []
+): [string, string][] {
+  if (lang === STRING_CONSTANTS.DEFAULT_LANG_KO) {
+    return [[key, value || key]];
+  }
+
+  if (pluralKeys.includes(key)) {
+    return [
+      [`${key}${PLURAL_SUFFIXES.ONE}`, STRING_CONSTANTS.EMPTY_STRING],
+      [`${key}${PLURAL_SUFFIXES.OTHER}`, STRING_CONSTANTS.EMPTY_STRING],
+    ];
+  }
+
+  return [[key, STRING_CONSTANTS.EMPTY_STRING]];
+}
+
+/**
+ * 복수형이 된 키의 기존 단수 번역을 key_one / key_other로 옮기고 단수 키 제거
+ * (이미 있는 복수형 항목은 그대로 유지)
+ */
+function migrateSingularEntry(
+  translations: { [key: string]: string },
+  key: string,
+  entries: [string, string][]
+): void {
+  const becamePlural = entries.every(([entryKey]) => entryKey !== key);
+  if (!becamePlural || !translations.hasOwnProperty(key)) {
+    return;
+  }
+
+  const singular = translations[key];
+  delete translations[key];
+  entries.forEach(([entryKey]) => {
+    if (!translations.hasOwnProperty(entryKey)) {
+      translations[entryKey] = singular;
+    }
+  });
+}
+
 /**
  * Google Sheets CSV 생성
  */
@@ -235,16 +293,11 @@ export function writeOutputFileWithNamespace(
         mergedTranslations = {};
 
         Object.keys(data).forEach((key) => {
-          if (lang === STRING_CONSTANTS.DEFAULT_LANG_KO) {
-            // 한국어는 키를 그대로 또는 defaultValue 사용
-            mergedTranslations[key] = data[key] || key;
-          } else if (lang === STRING_CONSTANTS.DEFAULT_LANG_EN) {
-            // 영어는 빈 문자열
-            mergedTranslations[key] = STRING_CONSTANTS.EMPTY_STRING;
-          } else {
-            // 기타 언어도 빈 문자열
-            mergedTranslations[key] = STRING_CONSTANTS.EMPTY_STRING;
-          }
+          seedTranslations(key, data[key], lang, config.pluralKeys).forEach(
+            ([entryKey, value]) => {
+              mergedTranslations[entryKey] = value;
+            }
+          );
         });
       } else {
         // 기본 모드: 기존 번역을 유지하고 새로운 키만 추가
@@ -252,19 +305,19 @@ export function writeOutputFileWithNamespace(
 
         let newKeysCount = 0;
         Object.keys(data).forEach((key) => {
-          if (!mergedTranslations.hasOwnProperty(key)) {
-            newKeysCount++;
-            if (lang === STRING_CONSTANTS.DEFAULT_LANG_KO) {
-              // 한국어는 키를 그대로 또는 defaultValue 사용
-              mergedTranslations[key] = data[key] || key;
-            } else if (lang === STRING_CONSTANTS.DEFAULT_LANG_EN) {
-              // 영어는 빈 문자열
-              mergedTranslations[key] = STRING_CONSTANTS.EMPTY_STRING;
-            } else {
-              // 기타 언어도 빈 문자열
-              mergedTranslations[key] = STRING_CONSTANTS.EMPTY_STRING;
+          const entries = seedTranslations(
+            key,
+            data[key],
+            lang,
+            config.pluralKeys
+          );
+          migrateSingularEntry(mergedTranslations, key, entries);
+          entries.forEach(([entryKey, value]) => {
+            if (!mergedTranslations.hasOwnProperty(entryKey)) {
+              newKeysCount++;
+              mergedTranslations[entryKey] = value;
             }
-          }
+          });
         });
       }
 
@@ -321,16 +374,11 @@ export function writeOutputFile(data: any, config: OutputConfig): void {
         mergedTranslations = {};
 
         Object.keys(data).forEach((key) => {
-          if (lang === STRING_CONSTANTS.DEFAULT_LANG_KO) {
-            // 한국어는 키를 그대로 또는 defaultValue 사용
-            mergedTranslations[key] = data[key] || key;
-          } else if (lang === STRING_CONSTANTS.DEFAULT_LANG_EN) {
-            // 영어는 빈 문자열
-            mergedTranslations[key] = STRING_CONSTANTS.EMPTY_STRING;
-          } else {
-            // 기타 언어도 빈 문자열
-            mergedTranslations[key] = STRING_CONSTANTS.EMPTY_STRING;
-          }
+          seedTranslations(key, data[key], lang, config.pluralKeys).forEach(
+            ([entryKey, value]) => {
+              mergedTranslations[entryKey] = value;
+            }
+          );
         });
       } else {
         // 기본 모드: 기존 번역을 유지하고 새로운 키만 추가
@@ -338,19 +386,19 @@ export function writeOutputFile(data: any, config: OutputConfig): void {
 
         let newKeysCount = 0;
         Object.keys(data).forEach((key) => {
-          if (!mergedTranslations.hasOwnProperty(key)) {
-            newKeysCount++;
-            if (lang === STRING_CONSTANTS.DEFAULT_LANG_KO) {
-              // 한국어는 키를 그대로 또는 defaultValue 사용
-              mergedTranslations[key] = data[key] || key;
-            } else if (lang === STRING_CONSTANTS.DEFAULT_LANG_EN) {
-              // 영어는 빈 문자열
-              mergedTranslations[key] = STRING_CONSTANTS.EMPTY_STRING;
-            } else {
-              // 기타 언어도 빈 문자열
-              mergedTranslations[key] = STRING_CONSTANTS.EMPTY_STRING;
+          const entries = seedTranslations(
+            key,
+            data[key],
+            lang,
+            config.pluralKeys
+          );
+          migrateSingularEntry(mergedTranslations, key, entries);
+          entries.forEach(([entryKey, value]) => {
+            if (!mergedTranslations.hasOwnProperty(entryKey)) {
+              newKeysCount++;
+              mergedTranslations[entryKey] = value;
             }
-          }
+          });
         });
       }
 
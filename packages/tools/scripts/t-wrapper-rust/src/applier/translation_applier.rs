@@ -1,7 +1,18 @@
 use swc_ecma_ast::*;
 use anyhow::Result;
+use std::collections::HashMap;
 use std::fs;
-use crate::parser::generate_code;
+use crate::manager::import_manager::{
+    add_import_if_needed, add_server_translation_import, create_translation_binding_with_callee,
+    ensure_use_client_directive, server_import_source, translation_callee, translation_callees,
+};
+use crate::ast::ast_helpers::is_component_initializer;
+use crate::ast::translation_scope::{translation_binding_in_body, TranslationCallees};
+use crate::parser::generate_code_with_comments;
+use crate::utils::constants::{RegexPatterns, StringConstants};
+use swc_common::comments::SingleThreadedComments;
+use swc_common::DUMMY_SP;
+use swc_ecma_visit::{VisitMut, VisitMutWith};
 
 #[derive(Debug, Clone)]
 pub struct ApplierConfig {
@@ -9,21 +20,310 @@ pub struct ApplierConfig {
     pub framework: Option<String>,
     pub server_translation_function: Option<String>,
     pub translation_import_source: String,
+    /// 컴포넌트 이름을 useTranslation(ns, { keyPrefix })로 넘길지 여부
+    pub key_prefix: bool,
 }
 
+/// 변환된 컴포넌트에 번역 바인딩과 import 추가
+///
+/// TypeScript 버전(applyTranslationsToAST)과 동일한 로직:
+/// 1. modified_functions에 있는 컴포넌트를 찾음 (다른 컴포넌트 안에 중첩된 컴포넌트 포함)
+///    (function 선언, const에 할당된 화살표/함수 표현식, memo/forwardRef로 감싼 함수,
+///    export default function, 이름 없는 export default는 "default"로 찾음)
+///    map 콜백, 이벤트 핸들러 같은 안쪽 함수에는 삽입하지 않음 (가장 가까운 컴포넌트의 t를 씀)
+/// 2. 이미 번역 훅에서 번역 함수를 구조 분해한 컴포넌트는 건너뜀
+///    (const { t } = useTranslation(), const { t: tr } = await getTranslations())
+///    props의 { t }나 const { t } = useClock()은 번역 함수가 아니므로 tr 등 다른 이름으로 삽입
+/// 3. 함수 body 첫 구문으로 `const { t } = useTranslation()` 삽입
+///    (Rules of Hooks: early return이나 조건문보다 항상 앞)
+///    translation_bindings에 있는 컴포넌트는 그 이름으로 바인딩: `const { t: tr } = useTranslation()`
+/// 4. 훅을 하나라도 삽입했으면 useTranslation import 추가
+///
+/// server 모드에서는 훅 대신 `const { t } = await getTranslations()`를 삽입하고
+/// 컴포넌트를 async로 바꾼 뒤 server_translation_function을 "<source>/server"에서 import
+/// 커스텀 훅(useLabel)은 async로 바꾸면 호출하는 쪽이 모두 깨지므로 server 모드에서도 useTranslation을 씀
+///
+/// Next.js client 모드에서 훅을 삽입했으면 모듈 맨 위에 'use client' 디렉티브를 추가
+///
+/// 번역 함수를 이미 별칭이나 네임스페이스로 import했으면 그 이름으로 호출
+/// (import { useTranslation as useT } → useT(), import * as i18n → i18n.useTranslation())
 pub fn apply_translations_to_ast(
-    _ast: &mut Module,
-    _modified_functions: &[String],
-    _config: &ApplierConfig,
+    ast: &mut Module,
+    modified_functions: &[String],
+    translation_bindings: &HashMap<String, String>,
+    config: &ApplierConfig,
 ) {
+    let hook_callee =
+        translation_callee(ast, &config.translation_import_source, StringConstants::USE_TRANSLATION);
+    let server_callee = is_server_mode(config).then(|| {
+        translation_callee(
+            ast,
+            &server_import_source(&config.translation_import_source),
+            server_translation_function(config),
+        )
+    });
+    let existing_callees = translation_callees(
+        ast,
+        &config.translation_import_source,
+        server_translation_function(config),
+    );
+    let mut inserter = HookInserter {
+        modified_functions,
+        translation_bindings,
+        hook_callee: &hook_callee,
+        server_callee: server_callee.as_ref(),
+        existing_callees: &existing_callees,
+        config,
+        inserted_hook: false,
+        inserted_server: false,
+    };
+    ast.visit_mut_with(&mut inserter);
+    let (inserted_hook, inserted_server) = (inserter.inserted_hook, inserter.inserted_server);
+
+    if inserted_hook
+        && config.framework.as_deref() == Some(StringConstants::FRAMEWORK_NEXTJS)
+        && config.mode.as_deref() == Some(StringConstants::MODE_CLIENT)
+    {
+        ensure_use_client_directive(ast);
+    }
+    if inserted_server {
+        add_server_translation_import(
+            ast,
+            &config.translation_import_source,
+            server_translation_function(config),
+        );
+    }
+    if inserted_hook {
+        add_import_if_needed(ast, &config.translation_import_source);
+    }
+}
+
+fn is_server_mode(config: &ApplierConfig) -> bool {
+    config.mode.as_deref() == Some(StringConstants::MODE_SERVER)
+}
+
+/// 서버 번역 함수 이름 (기본: getTranslations)
+fn server_translation_function(config: &ApplierConfig) -> &str {
+    config
+        .server_translation_function
+        .as_deref()
+        .unwrap_or(StringConstants::GET_SERVER_TRANSLATION)
+}
+
+/// modified_functions에 있는 컴포넌트를 찾아 번역 바인딩을 삽입하는 방문자
+struct HookInserter<'a> {
+    modified_functions: &'a [String],
+    translation_bindings: &'a HashMap<String, String>,
+    /// useTranslation (별칭/네임스페이스 반영)
+    hook_callee: &'a Expr,
+    /// server 모드의 서버 번역 함수 (client 모드면 None)
+    server_callee: Option<&'a Expr>,
+    /// 기존 번역 바인딩으로 인정할 callee (mode와 관계없이 훅과 서버 함수 모두)
+    existing_callees: &'a TranslationCallees,
+    config: &'a ApplierConfig,
+    inserted_hook: bool,
+    inserted_server: bool,
+}
+
+impl HookInserter<'_> {
+    /// 컴포넌트는 server 모드면 서버 번역 함수, 커스텀 훅은 항상 useTranslation
+    fn binding<'b>(&'b self, name: &'b str) -> Option<ComponentBinding<'b>> {
+        self.modified_functions
+            .iter()
+            .any(|function| function == name)
+            .then(|| {
+                let server_callee = self
+                    .server_callee
+                    .filter(|_| !RegexPatterns::react_hook().is_match(name));
+                ComponentBinding::new(
+                    name,
+                    self.translation_bindings,
+                    server_callee.unwrap_or(self.hook_callee),
+                    server_callee.is_some(),
+                    self.existing_callees,
+                    self.config,
+                )
+            })
+    }
+
+    fn record_insertion(&mut self, inserted: bool, server: bool) {
+        if server {
+            self.inserted_server |= inserted;
+        } else {
+            self.inserted_hook |= inserted;
+        }
+    }
+}
+
+impl VisitMut for HookInserter<'_> {
+    fn visit_mut_fn_decl(&mut self, fn_decl: &mut FnDecl) {
+        let name = fn_decl.ident.sym.to_string();
+        if let Some(binding) = self.binding(&name) {
+            let inserted = insert_into_function(&mut fn_decl.function, &binding);
+            let server = binding.server;
+            self.record_insertion(inserted, server);
+        }
+        fn_decl.visit_mut_children_with(self);
+    }
+
+    /// export default function Page() {}, 이름 없는 export default function () {}
+    fn visit_mut_export_default_decl(&mut self, export: &mut ExportDefaultDecl) {
+        if let DefaultDecl::Fn(FnExpr { ident, function }) = &mut export.decl {
+            let name = ident
+                .as_ref()
+                .map(|ident| ident.sym.to_string())
+                .unwrap_or_else(|| StringConstants::DEFAULT_EXPORT_COMPONENT.to_string());
+            if let Some(binding) = self.binding(&name) {
+                let inserted = insert_into_function(function, &binding);
+                let server = binding.server;
+                self.record_insertion(inserted, server);
+            }
+        }
+        export.visit_mut_children_with(self);
+    }
+
+    /// export default () => ..., export default memo(() => ...)
+    fn visit_mut_export_default_expr(&mut self, export: &mut ExportDefaultExpr) {
+        if let Some(binding) = self.binding(StringConstants::DEFAULT_EXPORT_COMPONENT) {
+            let inserted = match component_function_mut(&mut export.expr) {
+                Some(Expr::Arrow(arrow)) => insert_into_arrow(arrow, &binding),
+                Some(Expr::Fn(fn_expr)) => insert_into_function(&mut fn_expr.function, &binding),
+                _ => false,
+            };
+            let server = binding.server;
+            self.record_insertion(inserted, server);
+        }
+        export.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_var_declarator(&mut self, declarator: &mut VarDeclarator) {
+        if let (Pat::Ident(binding), Some(init)) = (&declarator.name, declarator.init.as_deref_mut()) {
+            let name = binding.id.sym.to_string();
+            if let Some(binding) = self.binding(&name) {
+                let inserted = match component_function_mut(init) {
+                    Some(Expr::Arrow(arrow)) => insert_into_arrow(arrow, &binding),
+                    Some(Expr::Fn(fn_expr)) => insert_into_function(&mut fn_expr.function, &binding),
+                    _ => false,
+                };
+                let server = binding.server;
+                self.record_insertion(inserted, server);
+            }
+        }
+        declarator.visit_mut_children_with(self);
+    }
+}
+
+/// 컴포넌트 변수 초기값의 함수: () => ..., function () {}, memo(() => ...)
+fn component_function_mut(init: &mut Expr) -> Option<&mut Expr> {
+    if !is_component_initializer(init) {
+        return None;
+    }
+    match init {
+        Expr::Call(call) => call.args.first_mut().map(|arg| arg.expr.as_mut()),
+        _ => Some(init),
+    }
+}
+
+/// 컴포넌트 하나에 삽입할 번역 바인딩 정보
+struct ComponentBinding<'a> {
+    /// 컴포넌트 이름 (key_prefix)
+    name: &'a str,
+    /// 번역 함수 이름 (기본 t, 충돌하면 tr, t2 등)
+    translation_function: &'a str,
+    callee: &'a Expr,
+    /// await getTranslations()로 바인딩하고 함수를 async로 바꿀지 여부
+    server: bool,
+    existing_callees: &'a TranslationCallees,
+    config: &'a ApplierConfig,
+}
+
+impl<'a> ComponentBinding<'a> {
+    fn new(
+        name: &'a str,
+        translation_bindings: &'a HashMap<String, String>,
+        callee: &'a Expr,
+        server: bool,
+        existing_callees: &'a TranslationCallees,
+        config: &'a ApplierConfig,
+    ) -> Self {
+        Self {
+            name,
+            translation_function: translation_bindings
+                .get(name)
+                .map(String::as_str)
+                .unwrap_or(StringConstants::TRANSLATION_FUNCTION),
+            callee,
+            server,
+            existing_callees,
+            config,
+        }
+    }
+
+    /// body가 이미 번역 훅에서 번역 함수를 구조 분해했는지
+    fn is_bound_in(&self, body: &BlockStmt) -> bool {
+        translation_binding_in_body(body, self.existing_callees).is_some()
+    }
+
+    /// client: const { t } = useTranslation(), server: const { t } = await getTranslations()
+    /// key_prefix가 켜져 있으면 컴포넌트 이름을 keyPrefix로 넘김
+    fn create_stmt(&self) -> Stmt {
+        let key_prefix = self.config.key_prefix.then_some(self.name);
+        create_translation_binding_with_callee(
+            self.callee.clone(),
+            self.translation_function,
+            self.server,
+            None,
+            key_prefix,
+        )
+    }
+}
+
+/// function 컴포넌트에 훅 삽입
+fn insert_into_function(function: &mut Function, binding: &ComponentBinding) -> bool {
+    let Some(body) = function.body.as_mut() else {
+        return false;
+    };
+    if binding.is_bound_in(body) {
+        return false;
+    }
+
+    body.stmts.insert(0, binding.create_stmt());
+    function.is_async |= binding.server;
+    true
+}
+
+/// 화살표 함수 컴포넌트에 훅 삽입
+/// 표현식 body는 블록으로 바꿈: () => <div /> → () => { const { t } = ...; return <div />; }
+fn insert_into_arrow(arrow: &mut ArrowExpr, binding: &ComponentBinding) -> bool {
+    if let BlockStmtOrExpr::Expr(expr) = arrow.body.as_mut() {
+        let expr = std::mem::replace(expr, Box::new(Expr::Invalid(Invalid { span: DUMMY_SP })));
+        *arrow.body = BlockStmtOrExpr::BlockStmt(BlockStmt {
+            span: DUMMY_SP,
+            stmts: vec![Stmt::Return(ReturnStmt {
+                span: DUMMY_SP,
+                arg: Some(expr),
+            })],
+            ctxt: Default::default(),
+        });
+    }
+    let BlockStmtOrExpr::BlockStmt(body) = arrow.body.as_mut() else {
+        return false;
+    };
+    if binding.is_bound_in(body) {
+        return false;
+    }
+
+    body.stmts.insert(0, binding.create_stmt());
+    arrow.is_async |= binding.server;
+    true
 }
 
 pub fn write_ast_to_file(
     ast: &Module,
     file_path: &str,
+    comments: Option<&SingleThreadedComments>,
 ) -> Result<()> {
-    let output = generate_code(ast)?;
+    let output = generate_code_with_comments(ast, comments)?;
     fs::write(file_path, output)?;
     Ok(())
 }
-
@@ -1,89 +1,454 @@
-/// AST 헬퍼 함수들
-/// 순수 함수로 구성되어 테스트하기 쉬움
+//! AST 헬퍼 함수들
+//! 순수 함수로 구성되어 테스트하기 쉬움
 
-use crate::utils::constants::{StringConstants, RegexPatterns};
+use crate::utils::constants::{JsxAttributes, NaturalLanguage, StringConstants, RegexPatterns};
+use anyhow::{bail, Context, Result};
+use regex::Regex;
+use std::collections::HashSet;
+use swc_common::{comments::SingleThreadedComments, BytePos, Span};
+use swc_ecma_ast::{
+    CallExpr, Callee, Expr, ExprStmt, MemberExpr, JSXAttrName, JSXElementName, Lit, MemberProp, Module, ModuleItem, Stmt,
+};
 
-/// i18n-ignore 주석이 노드 바로 위에 있는지 확인
-/// 파일의 원본 소스코드를 직접 검사하여 주석 감지
-/// 
-/// TypeScript 버전과 동일한 로직:
-/// 1. AST의 leadingComments 확인 (TODO: SWC AST로 구현)
-/// 2. 부모 노드의 leadingComments 확인 (TODO: SWC AST로 구현)
-/// 3. 소스코드 직접 검사 (현재 구현됨)
-pub fn has_ignore_comment(_path: (), source_code: Option<&str>) -> bool {
-    // TODO: SWC AST 노드의 leadingComments 확인
-    // if let Some(node) = path.node {
-    //     if let Some(comments) = node.leading_comments {
-    //         // 주석 확인 로직
-    //     }
-    // }
-    
-    // TODO: 부모 노드의 leadingComments 확인
-    // if let Some(parent) = path.parent_path {
-    //     // 부모 주석 확인 로직
-    // }
-    
-    // 3. 소스코드 직접 검사 (node.loc가 있는 경우)
-    if let Some(code) = source_code {
-        let lines: Vec<&str> = code.lines().collect();
-        
-        // 현재 라인과 바로 위 라인 검사 (최대 3줄 전까지)
-        for i in 0..lines.len().min(3) {
-            let line = lines[i];
-            if line.contains(StringConstants::I18N_IGNORE)
-                || line.contains(StringConstants::I18N_IGNORE_COMMENT)
-                || line.contains(StringConstants::I18N_IGNORE_BLOCK)
-                || line.contains(StringConstants::I18N_IGNORE_JSX)
+/// i18n-ignore 계열 주석 지시어
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IgnoreDirective {
+    /// `// i18n-ignore`: 주석이 붙은 노드와 그 구문 전체
+    Node,
+    /// `// i18n-ignore-next-line`: 주석 다음 줄
+    NextLine,
+    /// `// i18n-ignore-start`: 범위 시작
+    Start,
+    /// `// i18n-ignore-end`: 범위 끝
+    End,
+    /// `// i18n-ignore-file`: 파일 전체
+    File,
+}
+
+impl IgnoreDirective {
+    /// 주석 본문에서 지시어 파싱
+    /// 지시어 뒤에는 공백으로 구분해 이유를 적을 수 있음: `// i18n-ignore 외부 API 값`
+    /// JSDoc 형식은 줄마다 앞의 `*`를 떼고 첫 단어를 봄: `/** i18n-ignore */`, `/**\n * i18n-ignore\n */`
+    pub fn parse(comment_text: &str) -> Option<Self> {
+        let word = comment_text
+            .lines()
+            .flat_map(|line| line.trim_start().trim_start_matches('*').split_whitespace())
+            .next()?;
+        match word {
+            StringConstants::I18N_IGNORE => Some(Self::Node),
+            StringConstants::I18N_IGNORE_NEXT_LINE => Some(Self::NextLine),
+            StringConstants::I18N_IGNORE_START => Some(Self::Start),
+            StringConstants::I18N_IGNORE_END => Some(Self::End),
+            StringConstants::I18N_IGNORE_FILE => Some(Self::File),
+            _ => None,
+        }
+    }
+}
+
+/// 파싱 단계의 주석에서 수집한 i18n-ignore 지시어
+#[derive(Debug, Clone, Default)]
+pub struct IgnoreDirectives {
+    file: bool,
+    /// `i18n-ignore`가 leading comment로 붙은 노드의 시작 위치
+    node_starts: HashSet<BytePos>,
+    /// `i18n-ignore` 주석 자체의 위치 (JSX의 {/* i18n-ignore */} 확인용)
+    node_comments: Vec<Span>,
+    /// next-line, start/end 지시어로 무시되는 범위 [lo, hi)
+    ranges: Vec<(BytePos, BytePos)>,
+}
+
+impl IgnoreDirectives {
+    /// parse_file_with_comments가 반환한 주석에서 지시어 수집
+    /// 닫히지 않은 i18n-ignore-start는 파일 끝까지 적용
+    pub fn from_comments(comments: &SingleThreadedComments, source_code: &str) -> Self {
+        let mut directives = Self::default();
+        let (leading, trailing) = comments.borrow_all();
+
+        for (pos, leading_comments) in leading.iter() {
+            if leading_comments
+                .iter()
+                .any(|comment| IgnoreDirective::parse(&comment.text) == Some(IgnoreDirective::Node))
             {
-                return true;
+                directives.node_starts.insert(*pos);
+            }
+        }
+
+        let mut found: Vec<(Span, IgnoreDirective)> = leading
+            .values()
+            .chain(trailing.values())
+            .flatten()
+            .filter_map(|comment| IgnoreDirective::parse(&comment.text).map(|d| (comment.span, d)))
+            .collect();
+        found.sort_by_key(|(span, _)| span.lo);
+
+        let mut range_start = None;
+        for (span, directive) in found {
+            match directive {
+                IgnoreDirective::Node => directives.node_comments.push(span),
+                IgnoreDirective::NextLine => {
+                    if let Some(range) = next_line_range(source_code, span) {
+                        directives.ranges.push(range);
+                    }
+                }
+                IgnoreDirective::Start => {
+                    range_start.get_or_insert(span.hi);
+                }
+                IgnoreDirective::End => {
+                    if let Some(lo) = range_start.take() {
+                        directives.ranges.push((lo, span.lo));
+                    }
+                }
+                IgnoreDirective::File => directives.file = true,
             }
         }
+        if let Some(lo) = range_start {
+            directives.ranges.push((lo, BytePos(u32::MAX)));
+        }
+
+        directives
     }
-    
-    false
+
+    /// i18n-ignore-file이 있는지 확인
+    pub fn is_file_ignored(&self) -> bool {
+        self.file
+    }
+
+    /// pos에서 시작하는 노드에 i18n-ignore leading comment가 있는지 확인
+    pub fn has_leading_ignore(&self, pos: BytePos) -> bool {
+        self.node_starts.contains(&pos)
+    }
+
+    /// span 안에 i18n-ignore 주석이 있는지 확인
+    pub fn contains_ignore_comment(&self, span: Span) -> bool {
+        self.node_comments
+            .iter()
+            .any(|comment| span.lo <= comment.lo && comment.hi <= span.hi)
+    }
+
+    /// pos가 next-line 또는 start/end 범위 안에 있는지 확인
+    pub fn is_in_ignored_range(&self, pos: BytePos) -> bool {
+        self.ranges.iter().any(|&(lo, hi)| lo <= pos && pos < hi)
+    }
+}
+
+/// 주석이 끝나는 줄의 다음 줄 범위
+/// parse_file은 파일마다 새 SourceMap을 쓰므로 BytePos = 바이트 오프셋 + 1
+fn next_line_range(source_code: &str, comment: Span) -> Option<(BytePos, BytePos)> {
+    let offset = (comment.hi.0 as usize).checked_sub(1)?;
+    let rest = source_code.get(offset..)?;
+    let line_start = offset + rest.find('\n')? + 1;
+    let line_end = source_code[line_start..]
+        .find('\n')
+        .map_or(source_code.len(), |index| line_start + index);
+    Some((BytePos(line_start as u32 + 1), BytePos(line_end as u32 + 1)))
+}
+
+/// i18n-ignore 여부를 판단할 노드 경로
+/// Babel의 NodePath처럼 노드와 부모 구문 정보를 함께 전달
+#[derive(Debug, Clone, Copy)]
+pub struct NodePath<'a> {
+    /// 검사할 노드의 span
+    pub span: Span,
+    /// 노드를 감싸는 구문(statement)들의 span (바깥 → 안쪽)
+    pub statements: &'a [Span],
+    pub directives: &'a IgnoreDirectives,
+}
+
+/// i18n-ignore 주석이 노드에 적용되는지 확인
+///
+/// TypeScript 버전과 동일한 로직:
+/// 1. 노드의 leadingComments 확인
+/// 2. 부모 구문의 leadingComments 확인 (함수 선언에 붙이면 함수 전체)
+///
+/// 추가 지시어:
+/// - i18n-ignore-next-line: 노드가 주석 다음 줄에서 시작
+/// - i18n-ignore-start / i18n-ignore-end: 노드가 범위 안에서 시작
+/// - i18n-ignore-file: 파일 전체
+pub fn has_ignore_comment(path: &NodePath) -> bool {
+    let directives = path.directives;
+    directives.is_file_ignored()
+        || directives.has_leading_ignore(path.span.lo)
+        || path
+            .statements
+            .iter()
+            .any(|statement| directives.has_leading_ignore(statement.lo))
+        || directives.is_in_ignored_range(path.span.lo)
+}
+
+/// 문자열을 변환하지 않고 건너뛴 이유
+/// Transformer의 부모 문맥 스택에도 같은 값을 쌓아 사용
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SkipReason {
+    /// i18n-ignore 주석
+    IgnoreComment,
+    /// 이미 t() 호출의 인자
+    TranslationCall,
+    /// import/export 소스, import(), require()
+    ModuleSource,
+    /// 객체 프로퍼티 키, 계산된 멤버 키 (obj["키"])
+    PropertyKey,
+    /// TS 타입 위치의 리터럴
+    TsType,
+    /// TS enum 멤버 값
+    EnumMember,
+    /// declare 구문
+    AmbientDeclaration,
+    /// 'use client' 같은 디렉티브 프롤로그
+    Directive,
+    /// 자연어 점수가 임계값 근처라 변환하지 않음 (검토 필요)
+    LowConfidence,
+    /// 컴포넌트/커스텀 훅 밖이라 t를 바인딩할 스코프가 없음
+    NoComponentScope,
+}
+
+/// 변환하지 않고 건너뛴 문자열 (호출자에게 보고용)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkippedString {
+    pub value: String,
+    pub reason: SkipReason,
+    /// 1부터 시작하는 줄 번호 (위치를 알 수 없으면 None)
+    pub line: Option<usize>,
+}
+
+/// t()로 변환했지만 결과를 검토해야 하는 이유
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WarningKind {
+    /// i18next 포맷으로 옮길 수 없는 숫자/날짜 포맷 표현식을 그대로 interpolation 값으로 넘김
+    /// (value는 표현식 원문)
+    UnsupportedFormat,
+    /// interpolation 뒤에 받침에 따라 달라지는 조사가 원문 그대로 남음 (value는 표현식 원문 + 조사)
+    JosaParticle,
+}
+
+/// 변환했지만 검토가 필요한 문자열 (호출자에게 보고용, SkippedString과 구분)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TranslationWarning {
+    pub value: String,
+    pub kind: WarningKind,
+    /// 1부터 시작하는 줄 번호 (위치를 알 수 없으면 None)
+    pub line: Option<usize>,
+}
+
+/// 부모 문맥 스택에서 스킵 이유 찾기
+/// 가장 안쪽(마지막) 문맥을 우선
+pub fn parent_skip_reason(parents: &[SkipReason]) -> Option<SkipReason> {
+    parents.last().copied()
 }
 
 /// 문자열 리터럴 경로를 스킵해야 하는지 확인
 /// 
 /// TypeScript 버전과 동일한 로직:
 /// 1. i18n-ignore 주석이 있는 경우 스킵
-/// 2. 부모 노드에 i18n-ignore 주석이 있는 경우도 스킵
-/// 3. t() 함수로 이미 래핑된 경우 스킵 (TODO: SWC AST로 구현)
-/// 4. import 구문은 스킵 (TODO: SWC AST로 구현)
-/// 5. 객체 프로퍼티 KEY면 무조건 스킵 (TODO: SWC AST로 구현)
+/// 2. 부모 구문에 i18n-ignore 주석이 있는 경우도 스킵
+/// 3. t() 함수로 이미 래핑된 경우 스킵
+/// 4. import 구문은 스킵
+/// 5. 객체 프로퍼티 KEY면 무조건 스킵
+///
+/// 3~5와 TS 타입/enum/declare/디렉티브는 Transformer가 방문하면서 쌓은 부모 문맥(parents)으로 판단
 pub fn should_skip_path(
-    _path: (),
-    has_ignore_comment_fn: fn((), Option<&str>) -> bool,
-    source_code: Option<&str>,
-) -> bool {
-    // i18n-ignore 주석이 있는 경우 스킵
-    if has_ignore_comment_fn((), source_code) {
-        return true;
+    path: &NodePath,
+    parents: &[SkipReason],
+    has_ignore_comment_fn: fn(&NodePath) -> bool,
+) -> Option<SkipReason> {
+    // i18n-ignore 주석이 있는 경우 스킵 (노드 + 부모 구문)
+    if has_ignore_comment_fn(path) {
+        return Some(SkipReason::IgnoreComment);
     }
     
-    // TODO: 부모 노드에 i18n-ignore 주석이 있는 경우도 스킵
-    // if path.parent && has_ignore_comment_fn(path.parent_path, source_code) {
-    //     return true;
-    // }
-    
-    // TODO: t() 함수로 이미 래핑된 경우 스킵
-    // if t.isCallExpression(path.parent) && 
-    //    t.isIdentifier(path.parent.callee, { name: "t" }) {
-    //     return true;
-    // }
-    
-    // TODO: import 구문은 스킵
-    // let import_parent = path.find_parent(|p| t.isImportDeclaration(p.node));
-    // if import_parent.is_some() {
-    //     return true;
-    // }
-    
-    // TODO: 객체 프로퍼티 KEY면 무조건 스킵
-    // if t.isObjectProperty(path.parent) && path.parent.key == path.node {
-    //     return true;
-    // }
-    
-    false
+    parent_skip_reason(parents)
+}
+
+/// 컴포넌트 변수의 초기값인지 확인
+/// () => ..., function () {}, memo(() => ...), React.forwardRef(function (props, ref) {})
+pub fn is_component_initializer(init: &Expr) -> bool {
+    match init {
+        Expr::Arrow(_) | Expr::Fn(_) => true,
+        Expr::Call(CallExpr {
+            callee: Callee::Expr(callee),
+            args,
+            ..
+        }) => {
+            let wrapper = match callee.as_ref() {
+                Expr::Ident(ident) => Some(&*ident.sym),
+                Expr::Member(MemberExpr {
+                    prop: MemberProp::Ident(prop),
+                    ..
+                }) => Some(&*prop.sym),
+                _ => None,
+            };
+            wrapper.is_some_and(|wrapper| StringConstants::COMPONENT_WRAPPERS.contains(&wrapper))
+                && args.first().is_some_and(|arg| {
+                    arg.spread.is_none() && matches!(arg.expr.as_ref(), Expr::Arrow(_) | Expr::Fn(_))
+                })
+        }
+        _ => false,
+    }
+}
+
+/// 번역 함수 호출의 callee인지 확인
+/// t("..."), i18n.t("...")
+pub fn is_translation_callee(callee: &Expr) -> bool {
+    match callee {
+        Expr::Ident(ident) => &*ident.sym == StringConstants::TRANSLATION_FUNCTION,
+        Expr::Member(member) => matches!(
+            &member.prop,
+            MemberProp::Ident(prop) if &*prop.sym == StringConstants::TRANSLATION_FUNCTION
+        ),
+        _ => false,
+    }
+}
+
+/// span이 가리키는 소스코드의 줄 번호 (1부터 시작)
+/// parse_file은 파일마다 새 SourceMap을 쓰므로 BytePos는 1부터 시작
+pub fn line_of(source_code: &str, span: Span) -> Option<usize> {
+    if span.is_dummy() {
+        return None;
+    }
+    let offset = (span.lo.0 as usize).checked_sub(1)?;
+    let prefix = source_code.get(..offset)?;
+    Some(prefix.matches('\n').count() + 1)
+}
+
+/// span에 해당하는 원본 소스 코드 조각 (위치를 알 수 없으면 None)
+pub fn source_of(source_code: &str, span: Span) -> Option<&str> {
+    if span.is_dummy() {
+        return None;
+    }
+    let lo = (span.lo.0 as usize).checked_sub(1)?;
+    let hi = (span.hi.0 as usize).checked_sub(1)?;
+    source_code.get(lo..hi)
+}
+
+/// ScriptConfig의 감지 설정으로 번역 대상 텍스트 정규식 생성
+///
+/// 1. 커스텀 정규식(pattern)이 있으면 그대로 사용
+/// 2. 스크립트 목록이 있으면 유니코드 스크립트 클래스로 변환
+///    예: ["Hiragana", "Katakana", "Han"] → [\p{Hiragana}\p{Katakana}\p{Han}]
+/// 3. 둘 다 없으면 None (RegexPatterns::korean_text 사용)
+pub fn source_text_regex(scripts: Option<&[String]>, pattern: Option<&str>) -> Result<Option<Regex>> {
+    if let Some(pattern) = pattern {
+        let regex = Regex::new(pattern)
+            .with_context(|| format!("Invalid source text pattern: {}", pattern))?;
+        return Ok(Some(regex));
+    }
+
+    let Some(scripts) = scripts else {
+        return Ok(None);
+    };
+    if scripts.is_empty() {
+        bail!("source_scripts must contain at least one Unicode script");
+    }
+
+    let mut class = String::from("[");
+    for script in scripts {
+        let script = script.trim();
+        if script.is_empty() || !script.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+            bail!("Invalid Unicode script name: {:?}", script);
+        }
+        class.push_str(&format!("\\p{{{}}}", script));
+    }
+    class.push(']');
+
+    let regex = Regex::new(&class)
+        .with_context(|| format!("Unknown Unicode script in {:?}", scripts))?;
+    Ok(Some(regex))
+}
+
+/// 자연어 점수를 계산할 때의 문자열 위치
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextContext {
+    /// 일반 코드의 문자열 리터럴
+    Code,
+    /// JSX children 텍스트
+    JsxText,
+    /// 번역 대상 JSX 속성 값 (alt, title, placeholder, aria-*)
+    TranslatableAttribute,
+}
+
+/// 문자열이 사람이 읽는 자연어 UI 텍스트일 가능성 (0.0 ~ 1.0)
+///
+/// 점수를 올리는 특징:
+/// - 단어 수와 단어 사이 공백
+/// - 문장 부호 (마침표, 물음표, 느낌표, 쉼표 등)
+/// - 문장형 대문자 ("Save changes")
+/// - 글자 비율
+/// - JSX 텍스트나 번역 대상 속성 같은 문맥
+///
+/// 식별자(camelCase, snake_case), URL, 경로, CSS(클래스 목록, 선언)처럼 보이면 0.0
+pub fn natural_language_score(text: &str, context: TextContext) -> f64 {
+    let text = text.trim();
+    if !text.chars().any(char::is_alphabetic) || looks_like_code(text) {
+        return 0.0;
+    }
+
+    let words: Vec<&str> = text
+        .split_whitespace()
+        .filter(|word| word.chars().any(char::is_alphabetic))
+        .collect();
+
+    let mut score: f64 = match words.len() {
+        0 | 1 => NaturalLanguage::SINGLE_WORD_SCORE,
+        2 => NaturalLanguage::TWO_WORDS_SCORE,
+        _ => NaturalLanguage::MANY_WORDS_SCORE,
+    };
+    if words.len() > 1 {
+        score += NaturalLanguage::WORD_SPACING_WEIGHT;
+    }
+    if text.ends_with(['.', '!', '?', ':', '…']) || text.contains(", ") {
+        score += NaturalLanguage::PUNCTUATION_WEIGHT;
+    }
+    let mut chars = text.chars().filter(|c| c.is_alphabetic());
+    if let (Some(first), Some(second)) = (chars.next(), chars.next()) {
+        if first.is_uppercase() && second.is_lowercase() {
+            score += NaturalLanguage::SENTENCE_CASE_WEIGHT;
+        }
+    }
+    let letters = text.chars().filter(|c| c.is_alphabetic() || *c == ' ').count();
+    if letters as f64 / text.chars().count() as f64 >= NaturalLanguage::MIN_LETTER_RATIO {
+        score += NaturalLanguage::LETTER_RATIO_WEIGHT;
+    } else {
+        score -= NaturalLanguage::LETTER_RATIO_PENALTY;
+    }
+
+    score += match context {
+        TextContext::Code => 0.0,
+        TextContext::JsxText | TextContext::TranslatableAttribute => NaturalLanguage::UI_CONTEXT_WEIGHT,
+    };
+    score.clamp(0.0, 1.0)
+}
+
+/// 식별자, URL, 경로, CSS처럼 코드로 쓰이는 문자열인지 확인
+fn looks_like_code(text: &str) -> bool {
+    let has_space = text.contains(char::is_whitespace);
+
+    // URL, 이메일 링크
+    if text.contains("://") || text.starts_with("www.") || text.starts_with("mailto:") {
+        return true;
+    }
+    // 경로, MIME 타입: /users, ./file, application/json
+    if text.starts_with(['/', '.', '#', '@']) || (!has_space && text.contains(['/', '\\'])) {
+        return true;
+    }
+    // 식별자: onClick, user_id, SOME_KEY, btn-primary, config.key
+    // 끝의 문장 부호는 제외하고 확인 ("Loading...", "Done!")
+    if !has_space {
+        let core = text.trim_end_matches(['.', '!', '?', ':', '…']);
+        let has_inner_upper = core.chars().skip(1).any(char::is_uppercase)
+            && core.chars().any(char::is_lowercase);
+        if has_inner_upper || core.contains(['_', '-', '.', ':', '=']) {
+            return true;
+        }
+    }
+    // CSS: "flex items-center px-4", "color: red; margin: 0"
+    if text.contains(';') && text.contains(':') {
+        return true;
+    }
+    let tokens: Vec<&str> = text.split_whitespace().collect();
+    tokens.len() > 1
+        && tokens.iter().all(|token| {
+            token.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || "-:_/[]#%().".contains(c))
+        })
+        && tokens.iter().any(|token| token.contains(['-', ':']) || token.chars().any(|c| c.is_ascii_digit()))
 }
 
 /// React 컴포넌트 이름인지 확인
@@ -92,12 +457,100 @@ pub fn is_react_component(name: &str) -> bool {
         || RegexPatterns::react_hook().is_match(name)
 }
 
+/// 모듈 맨 앞 디렉티브 프롤로그의 문자열 값들 ('use client', 'use strict' 등)
+pub fn directive_prologue(module: &Module) -> impl Iterator<Item = String> + '_ {
+    module.body.iter().map_while(|item| match item {
+        ModuleItem::Stmt(Stmt::Expr(ExprStmt { expr, .. })) => match expr.as_ref() {
+            Expr::Lit(Lit::Str(str_lit)) => Some(str_lit.value.to_string_lossy().to_string()),
+            _ => None,
+        },
+        _ => None,
+    })
+}
+
 /// 서버 컴포넌트인지 확인
 /// 
 /// TypeScript 버전과 동일한 로직:
-/// await getServerTranslation() 패턴이 있으면 서버 컴포넌트로 판단
+/// await getServerTranslation() / await getTranslations() 패턴이 있으면 서버 컴포넌트로 판단
+/// 파일 단위 분류는 component_classifier::classify_module 사용
 pub fn is_server_component(source_code: &str) -> bool {
-    // await getServerTranslation() 패턴 확인
-    // 정규식으로 간단히 확인 (실제로는 AST로 확인하는 것이 더 정확하지만, 현재는 소스코드 직접 검사)
+    // 정규식으로 간단히 확인 (소스코드 직접 검사)
     RegexPatterns::server_component().is_match(source_code)
 }
+
+/// JSX 텍스트를 React가 실제로 렌더링하는 문자열로 정리
+///
+/// Babel의 cleanJSXElementLiteralChild와 동일한 규칙:
+/// 1. 줄 단위로 나누고 탭은 공백으로 취급
+/// 2. 첫 줄이 아니면 앞쪽 공백 제거, 마지막 줄이 아니면 뒤쪽 공백 제거
+/// 3. 공백만 남은 줄은 버리고 나머지를 공백 하나로 이어붙임
+///
+/// 예: "\n    안녕하세요\n    반갑습니다\n  " → "안녕하세요 반갑습니다"
+pub fn clean_jsx_text(text: &str) -> String {
+    let lines: Vec<&str> = text.split('\n').collect();
+    let last_index = lines.len() - 1;
+    let is_whitespace = |c: char| c == ' ' || c == '\t' || c == '\r';
+
+    lines
+        .iter()
+        .enumerate()
+        .filter_map(|(index, line)| {
+            let mut trimmed: &str = line;
+            if index != 0 {
+                trimmed = trimmed.trim_start_matches(is_whitespace);
+            }
+            if index != last_index {
+                trimmed = trimmed.trim_end_matches(is_whitespace);
+            }
+            if trimmed.is_empty() {
+                None
+            } else {
+                Some(trimmed.replace('\t', " "))
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// 정리된 JSX 텍스트를 (앞 공백, 본문, 뒤 공백)으로 분리
+/// 앞뒤 공백은 React가 렌더링하므로 t() 바깥에 그대로 남겨야 함
+pub fn split_jsx_whitespace(text: &str) -> (&str, &str, &str) {
+    let core = text.trim_matches(' ');
+    let leading_len = text.len() - text.trim_start_matches(' ').len();
+    let leading = &text[..leading_len];
+    let trailing = &text[leading_len + core.len()..];
+    (leading, core, trailing)
+}
+
+/// JSX 속성 이름을 문자열로 변환 (네임스페이스 속성은 "ns:name")
+pub fn jsx_attr_name(name: &JSXAttrName) -> String {
+    match name {
+        JSXAttrName::Ident(ident) => ident.sym.to_string(),
+        JSXAttrName::JSXNamespacedName(namespaced) => {
+            format!("{}:{}", namespaced.ns.sym, namespaced.name.sym)
+        }
+    }
+}
+
+/// JSX 엘리먼트 태그 이름 (멤버 표현식은 마지막 이름: Form.Input → "Input")
+pub fn jsx_element_name(name: &JSXElementName) -> String {
+    match name {
+        JSXElementName::Ident(ident) => ident.sym.to_string(),
+        JSXElementName::JSXMemberExpr(member) => member.prop.sym.to_string(),
+        JSXElementName::JSXNamespacedName(namespaced) => namespaced.name.sym.to_string(),
+    }
+}
+
+/// 항상 번역해야 하는 JSX 속성인지 확인 (alt, title, placeholder, aria-*)
+pub fn is_translatable_attribute(name: &str) -> bool {
+    JsxAttributes::TRANSLATABLE.contains(&name)
+        || name.starts_with(JsxAttributes::TRANSLATABLE_PREFIX)
+}
+
+/// 절대 번역하지 않는 JSX 속성인지 확인 (className, id, key, href, style, data-*)
+/// 네임스페이스 속성(xlink:href 등)도 번역하지 않음
+pub fn is_non_translatable_attribute(name: &str) -> bool {
+    JsxAttributes::NON_TRANSLATABLE.contains(&name)
+        || name.starts_with(JsxAttributes::NON_TRANSLATABLE_PREFIX)
+        || name.contains(':')
+}
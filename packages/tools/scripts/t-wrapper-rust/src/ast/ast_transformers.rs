@@ -1,24 +1,100 @@
-/// AST 변환 로직
-/// 문자열 리터럴, 템플릿 리터럴, JSX 텍스트를 t() 함수로 변환
+//! AST 변환 로직
+//! 문자열 리터럴, 템플릿 리터럴, JSX 텍스트를 t() 함수로 변환
 
-use crate::utils::constants::{StringConstants, RegexPatterns};
-use crate::ast::ast_helpers::{has_ignore_comment, should_skip_path};
+use crate::utils::constants::{
+    Formatting, InlineElements, Josa, KeyStrategies, NaturalLanguage, Pluralization, StringConstants,
+    RegexPatterns,
+};
+use crate::ast::ast_helpers::{
+    clean_jsx_text, has_ignore_comment, is_non_translatable_attribute, is_translatable_attribute,
+    is_translation_callee, jsx_attr_name, jsx_element_name, line_of, natural_language_score, parent_skip_reason,
+    should_skip_path, source_of, split_jsx_whitespace, IgnoreDirectives, NodePath, SkipReason,
+    SkippedString, TextContext, TranslationWarning, WarningKind,
+};
+use crate::ast::key_generator::{generate_key, slugify, KeyContext, KeyRegistry, KeyStrategy};
+use crate::ast::translation_scope::{ComponentScope, TranslationCallees};
+use crate::manager::import_manager::translation_callees;
+use crate::ast::deferred_constants::{prop_key_name, unwrap_constant_initializer, DeferredShape};
+use regex::Regex;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use swc_ecma_ast::*;
-use swc_ecma_visit::{VisitMut, VisitMutWith};
-use swc_common::DUMMY_SP;
+use swc_ecma_visit::{VisitMut, VisitMutWith, VisitWith};
+use swc_common::{comments::SingleThreadedComments, BytePos, EqIgnoreSpan, Span, Spanned, DUMMY_SP};
 
 /// 변환 결과
 #[derive(Debug, Clone)]
 pub struct TransformResult {
     pub was_modified: bool,
+    /// <Trans> 컴포넌트를 생성했는지 여부 (import 추가 필요)
+    pub uses_trans_component: bool,
+    /// 한국어가 있지만 문맥상 변환하지 않은 문자열
+    pub skipped: Vec<SkippedString>,
+    /// 변환했지만 검토가 필요한 문자열 (옮길 수 없는 포맷 등)
+    pub warnings: Vec<TranslationWarning>,
+    /// t 대신 다른 번역 함수 이름을 쓰는 컴포넌트 (컴포넌트 이름 → tr, t2 등)
+    pub translation_bindings: HashMap<String, String>,
+    /// 값을 지연 번역 마커로 감싼 모듈 최상위 상수 이름 (마커 import 필요)
+    pub deferred_constants: Vec<String>,
 }
 
 impl TransformResult {
     pub fn new(was_modified: bool) -> Self {
-        Self { was_modified }
+        Self {
+            was_modified,
+            uses_trans_component: false,
+            skipped: Vec::new(),
+            warnings: Vec::new(),
+            translation_bindings: HashMap::new(),
+            deferred_constants: Vec::new(),
+        }
     }
 }
 
+/// Transformer 설정
+/// ScriptConfig에서 변환 단계에 필요한 옵션만 전달
+#[derive(Debug, Clone, Default)]
+pub struct TransformerConfig {
+    /// 리치 텍스트 변환 방식 (None, "trans", "indexed")
+    pub rich_text: Option<String>,
+    /// 번역 대상 텍스트 감지 정규식 (None이면 한국어)
+    /// source_text_regex로 ScriptConfig의 source_scripts / source_text_pattern에서 생성
+    pub source_text: Option<Regex>,
+    /// 자연어 감지 임계값 (None이면 비활성화)
+    /// 정규식에 걸리지 않는 영어 등의 문자열도 natural_language_score가 이 값 이상이면 변환
+    pub natural_language_threshold: Option<f64>,
+    /// 번역 키 생성 전략 (기본: 원문)
+    pub key_strategy: KeyStrategy,
+    /// semantic 키에서 컴포넌트 이름을 빼고 useTranslation(ns, { keyPrefix })로 넘길지 여부
+    pub key_prefix: bool,
+    /// 복수형으로 인식할 숫자 뒤 단위 명사 (None이면 Pluralization::COUNTER_SUFFIXES)
+    pub counter_suffixes: Option<Vec<String>>,
+    /// 복수형으로 인식할 개수 변수 이름 (None이면 Pluralization::COUNTER_NAMES)
+    pub counter_names: Option<Vec<String>>,
+    /// interpolation 뒤 조사를 josa formatter 힌트로 변환 (false면 보고만 함)
+    pub josa_hints: bool,
+    /// 컴포넌트/커스텀 훅 밖 문자열 처리 방식 (t를 바인딩할 곳이 없음)
+    /// wrap_translations가 파일마다 정함
+    pub outside_component: OutsideComponent,
+    /// 모듈 최상위 상수 객체/배열의 값을 감쌀 지연 번역 마커 (None이면 비활성화)
+    /// 예: tKey → { PENDING: tKey("대기중") }, 컴포넌트 안 {STATUS_LABELS[s]} → {t(STATUS_LABELS[s])}
+    pub deferred_constants: Option<Box<Expr>>,
+    /// 기존 번역 바인딩으로 인정할 번역 훅/서버 번역 함수 (wrap_translations가 파일의 import로 정함)
+    /// None이면 transform_module_with_config가 기본 import 소스(i18nexus)의 import로 정함
+    pub translation_callees: Option<TranslationCallees>,
+}
+
+/// 컴포넌트/커스텀 훅 밖 문자열 처리 방식
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum OutsideComponent {
+    /// 컴포넌트 밖도 t()로 변환 (t 바인딩은 호출자가 책임, transform_module 기본)
+    #[default]
+    Translate,
+    /// 변환하지 않고 SkipReason::NoComponentScope로 보고
+    Skip,
+    /// 지정한 함수로 감쌈: i18n.t("..."), tKey("...")
+    Callee(Box<Expr>),
+}
+
 /// 함수 body 내의 AST 노드들을 변환
 /// 
 /// TypeScript 버전과 동일한 로직:
@@ -81,371 +157,2354 @@ pub struct TranslationTransformer {
     source_code: String,
     /// 변환된 함수 목록 (함수 이름)
     pub modified_functions: Vec<String>,
+    /// <Trans> 컴포넌트를 생성했는지 여부
+    pub uses_trans_component: bool,
+    /// 한국어가 있지만 문맥상 변환하지 않은 문자열
+    pub skipped: Vec<SkippedString>,
+    /// 변환했지만 검토가 필요한 문자열
+    pub warnings: Vec<TranslationWarning>,
+    /// t 대신 다른 번역 함수 이름을 쓰는 변환된 컴포넌트 (컴포넌트 이름 → 이름)
+    pub translation_bindings: HashMap<String, String>,
+    config: TransformerConfig,
+    /// 현재 방문 중인 노드의 부모 문맥 스택 (t() 인자, import 소스, 프로퍼티 키 등)
+    parents: Vec<SkipReason>,
+    /// 파싱 단계의 주석에서 수집한 i18n-ignore 지시어
+    ignore_directives: IgnoreDirectives,
+    /// 현재 노드를 감싸는 구문(statement) span 스택
+    statements: Vec<Span>,
+    /// 현재 노드를 감싸는 React 컴포넌트 이름 스택 (semantic 키)
+    components: Vec<String>,
+    /// 가장 안쪽 컴포넌트 자신의 스코프에서 변환이 일어났는지 (중첩 컴포넌트 제외)
+    component_modified: bool,
+    /// 현재 노드를 감싸는 컴포넌트에서 쓸 번역 함수 이름 스택 (t와 충돌하면 tr, t2 등)
+    translation_functions: Vec<String>,
+    /// 현재 노드를 감싸는 JSX 엘리먼트 (태그, id/name/type 구분자) 스택 (semantic 키)
+    jsx_elements: Vec<(String, Option<String>)>,
+    /// 현재 방문 중인 JSX 속성 이름 (semantic 키)
+    jsx_attribute: Option<String>,
+    /// 값이 할당되는 변수/프로퍼티 이름 (semantic 키)
+    binding: Option<String>,
+    /// 파일 안에서 생성한 키 (충돌 해결)
+    key_registry: KeyRegistry,
+    /// 지연 번역한 모듈 최상위 상수 이름 → 값 모양
+    deferred_constants: HashMap<String, DeferredShape>,
+    /// 지연 번역 단계에서 이미 방문한 상수 선언 (본 순회에서 다시 방문하지 않음)
+    deferred_declarators: HashSet<Span>,
+    /// 지연 번역 상수를 가리키는 콜백 인자 스택: OPTIONS.map((option) => ...)의 option
+    deferred_aliases: Vec<(String, DeferredShape)>,
+    /// 상수 값을 마커로 감싸는 중인지 (translation_callee가 마커를 반환)
+    deferring: bool,
 }
 
 impl TranslationTransformer {
     pub fn new(source_code: String) -> Self {
+        Self::with_config(source_code, TransformerConfig::default())
+    }
+
+    pub fn with_config(source_code: String, config: TransformerConfig) -> Self {
         Self {
             was_modified: false,
             source_code,
             modified_functions: Vec::new(),
+            uses_trans_component: false,
+            skipped: Vec::new(),
+            warnings: Vec::new(),
+            translation_bindings: HashMap::new(),
+            config,
+            parents: Vec::new(),
+            ignore_directives: IgnoreDirectives::default(),
+            statements: Vec::new(),
+            components: Vec::new(),
+            component_modified: false,
+            translation_functions: Vec::new(),
+            jsx_elements: Vec::new(),
+            jsx_attribute: None,
+            binding: None,
+            key_registry: KeyRegistry::default(),
+            deferred_constants: HashMap::new(),
+            deferred_declarators: HashSet::new(),
+            deferred_aliases: Vec::new(),
+            deferring: false,
         }
     }
 
-    /// t() 함수 호출 생성
-    /// TODO: 실제 AST 노드 생성 구현
-    fn create_t_call(&self, _value: &str) -> Expr {
-        // TODO: 실제 AST 노드 생성 구현
-        // 현재는 플레이스홀더
-        Expr::Ident(Ident {
-            span: DUMMY_SP,
-            sym: StringConstants::TRANSLATION_FUNCTION.into(),
-            optional: false,
-            ctxt: Default::default(),
-        })
+    /// i18n-ignore 지시어 설정
+    pub fn with_ignore_directives(mut self, ignore_directives: IgnoreDirectives) -> Self {
+        self.ignore_directives = ignore_directives;
+        self
     }
-    
-    /// 표현식에서 변수명 추출
-    /// Identifier: name 그대로 사용
-    /// MemberExpression: user.name → user_name
-    /// 기타: expr0, expr1 등으로 처리
-    fn extract_var_name(&self, expr: &Box<Expr>) -> String {
-        match expr.as_ref() {
-            Expr::Ident(ident) => ident.sym.to_string(),
-            Expr::Member(member) => {
-                let mut parts = Vec::new();
-                let mut current: &MemberExpr = member;
-                
-                loop {
-                    // property 이름 추출
-                    match &current.prop {
-                        MemberProp::Ident(prop_ident) => {
-                            parts.push(prop_ident.sym.to_string());
-                        }
-                        _ => {
-                            // 복잡한 property는 expr로 처리
-                            return format!("{}{}", StringConstants::EXPR_PREFIX, 0);
-                        }
-                    }
-                    
-                    // object 확인
-                    match &*current.obj {
-                        Expr::Member(nested_member) => {
-                            current = nested_member;
-                        }
-                        Expr::Ident(obj_ident) => {
-                            parts.push(obj_ident.sym.to_string());
-                            break;
-                        }
-                        _ => {
-                            // 복잡한 object는 expr로 처리
-                            return format!("{}{}", StringConstants::EXPR_PREFIX, 0);
-                        }
-                    }
-                }
-                
-                parts.reverse();
-                parts.join(StringConstants::MEMBER_SEPARATOR)
-            }
-            _ => {
-                // 복잡한 표현식은 expr0, expr1 등으로 처리
-                format!("{}{}", StringConstants::EXPR_PREFIX, 0)
-            }
+
+    /// 번역 대상 언어의 텍스트가 포함되어 있는지 확인
+    /// 문자열, 템플릿, JSX 텍스트 감지에 모두 같은 기준을 사용
+    fn is_source_text(&self, text: &str) -> bool {
+        self.config
+            .source_text
+            .as_ref()
+            .unwrap_or_else(|| RegexPatterns::korean_text())
+            .is_match(text)
+    }
+
+    /// 원본 언어 정규식 또는 자연어 점수로 번역 대상인지 판단
+    fn match_text(&self, text: &str, context: TextContext) -> TextMatch {
+        if self.is_source_text(text) {
+            return TextMatch::Translate;
+        }
+        let Some(threshold) = self.config.natural_language_threshold else {
+            return TextMatch::Ignore;
+        };
+
+        let score = natural_language_score(text, context);
+        if score >= threshold {
+            TextMatch::Translate
+        } else if score >= threshold - NaturalLanguage::BORDERLINE_MARGIN {
+            TextMatch::Borderline
+        } else {
+            TextMatch::Ignore
         }
     }
-}
 
-impl VisitMut for TranslationTransformer {
-    /// FunctionDeclaration 변환
-    /// TypeScript 버전과 동일한 로직:
-    /// 1. React 컴포넌트인지 확인
-    /// 2. 함수 body를 변환
-    /// 3. 변환된 경우 함수 이름 저장
-    fn visit_mut_fn_decl(&mut self, func: &mut FnDecl) {
-        // React 컴포넌트인지 확인
-        let name = func.ident.sym.to_string();
-        if crate::ast_helpers::is_react_component(&name) {
-            // 함수 body 변환 (자식 노드 방문으로 자동 처리됨)
-            let before_modified = self.was_modified;
-            func.visit_mut_children_with(self);
-            // 변환되었으면 함수 이름 저장
-            if self.was_modified && !before_modified {
-                self.modified_functions.push(name);
+    /// 번역 대상이면 true, 임계값 근처면 변환하지 않고 LowConfidence로 보고
+    fn should_translate(&mut self, text: &str, context: TextContext, span: Span) -> bool {
+        match self.match_text(text, context) {
+            TextMatch::Translate if self.outside_component_scope() => {
+                self.report_skip(text.trim(), SkipReason::NoComponentScope, span);
+                false
             }
-            return;
+            TextMatch::Translate => true,
+            TextMatch::Borderline => {
+                self.report_skip(text.trim(), SkipReason::LowConfidence, span);
+                false
+            }
+            TextMatch::Ignore => false,
         }
-        // React 컴포넌트가 아니면 자식 노드만 방문
-        func.visit_mut_children_with(self);
     }
 
-    /// ArrowFunctionExpression 변환
-    /// TypeScript 버전과 동일한 로직:
-    /// 1. 변수 선언의 일부인지 확인
-    /// 2. React 컴포넌트인지 확인
-    /// 3. 함수 body를 변환
-    /// 4. 변환된 경우 함수 이름 저장
-    fn visit_mut_expr(&mut self, expr: &mut Expr) {
-        // ArrowFunctionExpression 처리
-        if let Expr::Arrow(arrow) = expr {
-            // TODO: 부모 노드 확인하여 변수 선언인지 확인
-            // 현재는 일단 모든 ArrowFunction을 처리
-            let before_modified = self.was_modified;
-            arrow.visit_mut_children_with(self);
-            // 변환되었으면 기록 (이름은 나중에 부모에서 확인)
-            if self.was_modified && !before_modified {
-                // TODO: 변수 이름 추출
+    /// span 위치의 노드를 스킵해야 하는 이유
+    /// i18n-ignore 주석(노드 + 부모 구문)을 먼저 보고, 없으면 부모 문맥 스택을 봄
+    fn skip_reason(&self, span: Span) -> Option<SkipReason> {
+        let path = NodePath {
+            span,
+            statements: &self.statements,
+            directives: &self.ignore_directives,
+        };
+        should_skip_path(&path, &self.parents, has_ignore_comment)
+    }
+
+    /// JSX 속성 값 변환 (visit_mut_jsx_attr에서 속성 문맥을 설정한 뒤 호출)
+    fn transform_jsx_attr(&mut self, name: &str, attr: &mut JSXAttr) {
+        if let Some(reason) = self.skip_reason(attr.span) {
+            if let Some(JSXAttrValue::Str(str_lit)) = &attr.value {
+                let value = str_lit.value.to_string_lossy().to_string();
+                if self.is_source_text(&value) {
+                    self.report_skip(&value, reason, str_lit.span);
+                }
             }
+            self.visit_with_parent(reason, attr);
             return;
         }
 
-        // Expression 변환 (StringLiteral을 t() 호출로 교체)
-        // TypeScript 버전과 동일한 로직:
-        // 1. StringLiteral 감지
-        // 2. 한국어 텍스트가 포함된 문자열만 처리
-        // 3. t() 함수 호출로 변환
-        if let Expr::Lit(Lit::Str(str_lit)) = expr {
-            // Wtf8Atom을 &str로 변환하여 한국어 체크
-            // 방법: to_string_lossy() 직접 사용 (최신 SWC API)
-            let str_value: &str = &str_lit.value.to_string_lossy();
-            
-            // 한국어가 포함되어 있는지 확인
-            if RegexPatterns::korean_text().is_match(str_value) {
-                self.was_modified = true;
-                
-                // t() 함수 호출 생성
-                let t_call = Expr::Call(CallExpr {
-                    span: DUMMY_SP,
-                    callee: Callee::Expr(Box::new(Expr::Ident(Ident {
-                        span: DUMMY_SP,
-                        sym: StringConstants::TRANSLATION_FUNCTION.into(),
-                        optional: false,
-                        ctxt: Default::default(),
-                    }))),
-                    args: vec![ExprOrSpread {
-                        spread: None,
-                        expr: Box::new(Expr::Lit(Lit::Str(Str {
-                            span: str_lit.span,
-                            value: str_lit.value.clone(),
-                            raw: None,
-                        }))),
-                    }],
-                    type_args: None,
-                    ctxt: Default::default(),
-                });
-                
-                // 현재 Expression을 t() 호출로 교체
-                *expr = t_call;
-                // 변환 후에는 자식 노드를 방문하지 않음 (무한 재귀 방지)
+        if let Some(JSXAttrValue::Str(str_lit)) = &attr.value {
+            let value = str_lit.value.to_string_lossy();
+            let context = if is_translatable_attribute(name) {
+                TextContext::TranslatableAttribute
+            } else {
+                TextContext::Code
+            };
+            if self.should_translate(&value, context, str_lit.span) {
+                self.mark_modified();
+
+                // 속성 값에 bare call은 올 수 없으므로 ExpressionContainer로 감싸기
+                let t_call = self.create_t_call(str_lit.span, &value, None);
+                attr.value = Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+                    span: str_lit.span,
+                    expr: JSXExpr::Expr(Box::new(t_call)),
+                }));
                 return;
             }
         }
-        
-        // 재귀적으로 자식 노드 방문
-        expr.visit_mut_children_with(self);
+
+        // title={`${name}님`} 같은 ExpressionContainer는 visit_mut_expr에서 처리
+        attr.visit_mut_children_with(self);
     }
-    
-    /// StringLiteral 변환 (하위 호환성을 위해 유지)
-    fn visit_mut_str(&mut self, n: &mut Str) {
-        // visit_mut_expr에서 처리하므로 여기서는 아무것도 하지 않음
-        let _ = n;
+
+    /// 구문 span을 스택에 쌓은 상태로 자식 노드 방문
+    fn visit_statement<N>(&mut self, span: Span, node: &mut N)
+    where
+        N: VisitMutWith<Self>,
+    {
+        self.statements.push(span);
+        node.visit_mut_children_with(self);
+        self.statements.pop();
     }
 
-    /// TemplateLiteral 변환
-    /// TypeScript 버전과 동일한 로직:
-    /// 1. i18n-ignore 주석이 있는 경우 스킵
-    /// 2. 이미 t()로 래핑된 경우 스킵
-    /// 3. 템플릿 리터럴의 모든 부분에 하나라도 한국어가 있는지 확인
-    /// 4. 템플릿 리터럴을 i18next interpolation 형식으로 변환
-    ///    예: `안녕 ${name}` → t(`안녕 {{name}}`, { name })
-    fn visit_mut_tpl(&mut self, n: &mut Tpl) {
-        // TODO: shouldSkipPath 및 hasIgnoreComment로 스킵 확인
-        // TODO: 이미 t()로 래핑된 경우 스킵
-        
-        // 템플릿 리터럴의 quasis(문자열 부분)에서 한국어 확인
-        let has_korean = n.quasis.iter().any(|quasi| {
-            let value: &str = if let Some(cooked) = &quasi.cooked {
-                &cooked.to_string_lossy()
-            } else {
-                &quasi.raw.to_string()
-            };
-            RegexPatterns::korean_text().is_match(value)
+    /// JSX children 중 i18n-ignore가 적용되는 자식 표시
+    /// {/* i18n-ignore */}는 바로 다음 자식(공백 텍스트 제외)에 적용
+    fn ignored_jsx_children(&self, children: &[JSXElementChild]) -> Vec<bool> {
+        let mut ignore_next = false;
+        children
+            .iter()
+            .map(|child| {
+                if let JSXElementChild::JSXExprContainer(JSXExprContainer {
+                    span,
+                    expr: JSXExpr::JSXEmptyExpr(_),
+                }) = child
+                {
+                    ignore_next |= self.ignore_directives.contains_ignore_comment(*span);
+                    return false;
+                }
+                if matches!(child, JSXElementChild::JSXText(text) if text.value.trim().is_empty()) {
+                    return false;
+                }
+                std::mem::take(&mut ignore_next)
+                    || self.skip_reason(jsx_child_start(child)).is_some()
+            })
+            .collect()
+    }
+
+    /// 부모 문맥을 쌓은 상태로 자식 노드 방문
+    fn visit_with_parent<N>(&mut self, reason: SkipReason, node: &mut N)
+    where
+        N: VisitMutWith<Self>,
+    {
+        self.parents.push(reason);
+        node.visit_mut_children_with(self);
+        self.parents.pop();
+    }
+
+    /// 건너뛴 문자열 기록
+    fn report_skip(&mut self, value: &str, reason: SkipReason, span: Span) {
+        self.skipped.push(SkippedString {
+            value: value.to_string(),
+            reason,
+            line: line_of(&self.source_code, span),
         });
-        
-        if !has_korean {
-            n.visit_mut_children_with(self);
-            return;
-        }
-        
-        self.was_modified = true;
-        
-        // 표현식이 없으면 단순 문자열로 처리
-        if n.exprs.is_empty() {
-            if let Some(first_quasi) = n.quasis.first() {
-                let raw_value = if let Some(cooked) = &first_quasi.cooked {
-                    cooked.clone()
-                } else {
-                    // Atom을 Wtf8Atom으로 변환 필요
-                    // 현재는 raw를 그대로 사용
-                    first_quasi.raw.clone().into()
-                };
-                
-                let t_call = Expr::Call(CallExpr {
-                    span: n.span,
-                    callee: Callee::Expr(Box::new(Expr::Ident(Ident {
-                        span: DUMMY_SP,
-                        sym: StringConstants::TRANSLATION_FUNCTION.into(),
-                        optional: false,
-                        ctxt: Default::default(),
-                    }))),
-                    args: vec![ExprOrSpread {
-                        spread: None,
-                        expr: Box::new(Expr::Lit(Lit::Str(Str {
-                            span: first_quasi.span,
-                            value: raw_value,
-                            raw: None,
-                        }))),
-                    }],
-                    type_args: None,
-                    ctxt: Default::default(),
-                });
-                
-                // Tpl을 Expr로 변환하여 교체
-                // 현재는 플래그만 설정 (실제 교체는 상위 레벨에서 처리 필요)
-            }
-            n.visit_mut_children_with(self);
-            return;
+    }
+
+    /// import/export 구문의 모듈 경로 기록: import data from "./데이터"
+    fn report_module_source(&mut self, src: &Str) {
+        let value = src.value.to_string_lossy().to_string();
+        if self.is_source_text(&value) {
+            self.report_skip(&value, SkipReason::ModuleSource, src.span);
         }
-        
-        // i18next interpolation 형식으로 변환
-        // `안녕 ${name}` → `안녕 {{name}}`
-        let mut i18next_string = String::new();
-        let mut interpolation_vars = Vec::new();
-        
-        for (index, quasi) in n.quasis.iter().enumerate() {
-            let raw_value = if let Some(cooked) = &quasi.cooked {
-                cooked.to_string_lossy().to_string()
+    }
+
+    /// 변환했지만 검토가 필요한 문자열 기록
+    fn report_warning(&mut self, value: &str, kind: WarningKind, span: Span) {
+        self.warnings.push(TranslationWarning {
+            value: value.to_string(),
+            kind,
+            line: line_of(&self.source_code, span),
+        });
+    }
+
+    /// 현재 위치의 키 생성 문맥
+    /// keyPrefix를 쓰면 컴포넌트 이름은 useTranslation 옵션으로 넘기므로 키에서 뺌
+    fn key_context(&self) -> KeyContext {
+        let (element, qualifier) = self.jsx_elements.last().cloned().unzip();
+        KeyContext {
+            component: if self.config.key_prefix {
+                None
             } else {
-                quasi.raw.to_string()
-            };
-            i18next_string.push_str(&raw_value);
-            
-            if index < n.exprs.len() {
-                let expr = &n.exprs[index];
-                
-                // 변수명 추출
-                let var_name = self.extract_var_name(expr);
-                
-                // i18next 형식: {{varName}}
-                i18next_string.push_str(StringConstants::INTERPOLATION_START);
-                i18next_string.push_str(&var_name);
-                i18next_string.push_str(StringConstants::INTERPOLATION_END);
-                
-                // interpolation 객체에 추가
-                interpolation_vars.push(PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
-                    key: PropName::Ident(IdentName {
-                        span: DUMMY_SP,
-                        sym: var_name.into(),
-                    }),
-                    value: expr.clone(),
-                }))));
-            }
+                self.components.last().cloned()
+            },
+            binding: self.binding.clone(),
+            element,
+            qualifier: qualifier.flatten(),
+            attribute: self.jsx_attribute.clone(),
         }
-        
-        // t("안녕 {{name}}", { name: name })
+    }
+
+    /// 설정한 전략으로 메시지의 번역 키 생성
+    /// 문맥이나 슬러그에서 만든 키는 파일 안에서 충돌하지 않도록 접미사를 붙임
+    fn translation_key(&mut self, message: &str) -> String {
+        let key = generate_key(self.config.key_strategy, message, &self.key_context());
+        match self.config.key_strategy {
+            KeyStrategy::Source | KeyStrategy::Hash => key,
+            KeyStrategy::Slug | KeyStrategy::Semantic => self.key_registry.resolve(key, message),
+        }
+    }
+
+    /// 값이 할당되는 이름을 문맥에 두고 자식 노드 방문 (semantic 키)
+    fn visit_with_binding<N>(&mut self, binding: Option<String>, node: &mut N)
+    where
+        N: VisitMutWith<Self>,
+    {
+        let outer_binding = std::mem::replace(&mut self.binding, binding);
+        node.visit_mut_children_with(self);
+        self.binding = outer_binding;
+    }
+
+    /// t() 함수 호출 생성
+    /// `t("key")` 또는 interpolation 객체가 있으면 `t("key", { ... })`
+    /// 키 전략이 원문이 아닌 키를 만들면 원문을 `defaultValue`로 추가
+    fn create_t_call(&mut self, span: Span, message: &str, options: Option<ObjectLit>) -> Expr {
+        let key = self.translation_key(message);
+        let options = if key == message {
+            options
+        } else {
+            let mut options = options.unwrap_or(ObjectLit {
+                span: DUMMY_SP,
+                props: Vec::new(),
+            });
+            options.props.insert(
+                0,
+                create_interpolation_prop(
+                    KeyStrategies::DEFAULT_VALUE,
+                    Box::new(Expr::Lit(Lit::Str(Str {
+                        span: DUMMY_SP,
+                        value: message.into(),
+                        raw: None,
+                    }))),
+                ),
+            );
+            Some(options)
+        };
+
         let mut args = vec![ExprOrSpread {
             spread: None,
             expr: Box::new(Expr::Lit(Lit::Str(Str {
                 span: DUMMY_SP,
-                value: i18next_string.into(),
+                value: key.as_str().into(),
                 raw: None,
             }))),
         }];
-        
-        // interpolation 객체가 있으면 두 번째 인자로 추가
-        if !interpolation_vars.is_empty() {
+
+        if let Some(options) = options {
             args.push(ExprOrSpread {
                 spread: None,
-                expr: Box::new(Expr::Object(ObjectLit {
-                    span: DUMMY_SP,
-                    props: interpolation_vars,
-                })),
+                expr: Box::new(Expr::Object(options)),
             });
         }
-        
-        let t_call = Expr::Call(CallExpr {
-            span: n.span,
-            callee: Callee::Expr(Box::new(Expr::Ident(Ident {
-                span: DUMMY_SP,
-                sym: StringConstants::TRANSLATION_FUNCTION.into(),
-                optional: false,
-                ctxt: Default::default(),
-            }))),
+
+        Expr::Call(CallExpr {
+            span,
+            callee: Callee::Expr(Box::new(self.translation_callee())),
             args,
             type_args: None,
             ctxt: Default::default(),
-        });
-        
-        // Tpl을 Expr로 변환하여 교체
-        // 현재는 플래그만 설정 (실제 교체는 상위 레벨에서 처리 필요)
-        n.visit_mut_children_with(self);
+        })
     }
 
-    /// JSXText 변환
+    /// 템플릿 리터럴을 t() 호출로 변환
     /// TypeScript 버전과 동일한 로직:
-    /// 1. i18n-ignore 주석이 있는 경우 스킵
-    /// 2. 빈 텍스트나 공백만 있는 경우 스킵
-    /// 3. 한국어가 포함된 텍스트만 처리
-    /// 4. t() 함수 호출로 감싸기
-    fn visit_mut_jsx_text(&mut self, n: &mut JSXText) {
-        // TODO: hasIgnoreComment로 스킵 확인
-        
-        // 빈 텍스트나 공백만 있는 경우 스킵
-        let text_value: &str = &n.value.to_string();
-        let trimmed = text_value.trim();
-        if trimmed.is_empty() {
-            return;
+    /// 1. quasis 중 하나라도 한국어가 있는지 확인
+    /// 2. 표현식이 없으면 단순 문자열로 처리: `안녕` → t("안녕")
+    /// 3. 표현식이 있으면 i18next interpolation 형식으로 변환
+    ///    예: `안녕 ${name}` → t("안녕 {{name}}", { name })
+    ///
+    /// 한국어가 없으면 None 반환
+    fn tpl_to_t_call(&mut self, tpl: &Tpl) -> Option<Expr> {
+        let text: String = tpl.quasis.iter().map(quasi_value).collect();
+        if !self.should_translate(&text, TextContext::Code, tpl.span) {
+            return None;
         }
-        
-        // 한국어가 포함되어 있는지 확인
-        if !RegexPatterns::korean_text().is_match(text_value) {
-            return;
+
+        let mut parts = Vec::with_capacity(tpl.quasis.len() + tpl.exprs.len());
+        for (index, quasi) in tpl.quasis.iter().enumerate() {
+            parts.push(MessagePart::Text(quasi_value(quasi)));
+            if let Some(expr) = tpl.exprs.get(index) {
+                parts.push(MessagePart::Expr(expr.clone()));
+            }
         }
-        
+
+        Some(self.create_interpolated_t_call(tpl.span, parts))
+    }
+
+    /// React 컴포넌트(또는 훅) 하위 노드 방문
+    /// 이 컴포넌트 안에서 변환이 일어났으면 modified_functions에 이름을 저장 (applier가 훅 삽입)
+    /// 중첩 컴포넌트 안의 문자열은 가장 가까운 컴포넌트에만 기록 (바깥 컴포넌트는 훅을 받지 않음)
+    ///
+    /// 변환 전에 컴포넌트 스코프를 훑어 번역 함수 이름을 정함 (t와 충돌하면 tr, t2 등)
+    fn visit_component<N>(&mut self, name: String, node: &mut N)
+    where
+        N: VisitMutWith<Self> + for<'a> VisitWith<ComponentScope<'a>>,
+    {
+        let fallback_callees = TranslationCallees::default();
+        let callees = self.config.translation_callees.as_ref().unwrap_or(&fallback_callees);
+        let translation_function = ComponentScope::collect(&*node, callees).translation_function_name();
+        let outer_modified = std::mem::replace(&mut self.component_modified, false);
+        self.components.push(name.clone());
+        self.translation_functions.push(translation_function);
+        node.visit_mut_children_with(self);
+        let translation_function = self.translation_functions.pop();
+        self.components.pop();
+        let modified = std::mem::replace(&mut self.component_modified, outer_modified);
+        if modified && !self.modified_functions.contains(&name) {
+            if let Some(translation_function) = translation_function
+                .filter(|function| function != StringConstants::TRANSLATION_FUNCTION)
+            {
+                self.translation_bindings.insert(name.clone(), translation_function);
+            }
+            self.modified_functions.push(name);
+        }
+    }
+
+    /// 문자열을 변환했음을 기록 (가장 안쪽 컴포넌트가 훅을 받음)
+    fn mark_modified(&mut self) {
         self.was_modified = true;
-        
-        // t() 함수 호출 생성
-        let t_call = Expr::Call(CallExpr {
-            span: DUMMY_SP,
-            callee: Callee::Expr(Box::new(Expr::Ident(Ident {
+        self.component_modified = true;
+    }
+
+    /// t를 바인딩할 컴포넌트/커스텀 훅 밖이라 변환하지 않아야 하는지
+    fn outside_component_scope(&self) -> bool {
+        self.config.outside_component == OutsideComponent::Skip && self.components.is_empty()
+    }
+
+    /// 생성할 번역 호출의 callee
+    /// 상수를 지연 번역 중이면 마커
+    /// 컴포넌트 밖이고 OutsideComponent::Callee면 그 함수, 아니면 현재 컴포넌트의 번역 함수
+    fn translation_callee(&self) -> Expr {
+        if let (true, Some(marker)) = (self.deferring, &self.config.deferred_constants) {
+            return (**marker).clone();
+        }
+        match &self.config.outside_component {
+            OutsideComponent::Callee(callee) if self.components.is_empty() => (**callee).clone(),
+            _ => Expr::Ident(Ident {
                 span: DUMMY_SP,
-                sym: StringConstants::TRANSLATION_FUNCTION.into(),
+                sym: self.translation_function().into(),
                 optional: false,
                 ctxt: Default::default(),
-            }))),
+            }),
+        }
+    }
+
+    /// 현재 컴포넌트에서 쓸 번역 함수 이름 (컴포넌트 밖이면 t)
+    fn translation_function(&self) -> &str {
+        self.translation_functions
+            .last()
+            .map(String::as_str)
+            .unwrap_or(StringConstants::TRANSLATION_FUNCTION)
+    }
+
+    /// 번역 함수 호출의 callee인지 확인 (t, i18n.t, 현재 컴포넌트의 tr, tKey 마커 등)
+    fn is_translation_call(&self, callee: &Expr) -> bool {
+        is_translation_callee(callee)
+            || matches!(callee, Expr::Ident(ident) if &*ident.sym == self.translation_function())
+            || matches!(
+                &self.config.outside_component,
+                OutsideComponent::Callee(outside) if outside.as_ref().eq_ignore_span(callee)
+            )
+            || self.is_deferred_marker(callee)
+    }
+
+    /// 지연 번역 마커 호출의 callee인지 확인 (tKey)
+    fn is_deferred_marker(&self, callee: &Expr) -> bool {
+        self.config
+            .deferred_constants
+            .as_ref()
+            .is_some_and(|marker| marker.as_ref().eq_ignore_span(callee))
+    }
+
+    /// 모듈 최상위 const 객체/배열 상수를 지연 번역
+    /// 본 순회 전에 실행해 상수보다 위에 선언된 컴포넌트에서도 사용처를 찾을 수 있게 함
+    ///
+    /// 예: export const STATUS_LABELS = { PENDING: "대기중" } as const
+    ///     → export const STATUS_LABELS = { PENDING: tKey("대기중") } as const
+    fn defer_module_constants(&mut self, items: &mut [ModuleItem]) {
+        if self.config.deferred_constants.is_none() {
+            return;
+        }
+        for item in items {
+            let span = item.span();
+            let var_decl = match item {
+                ModuleItem::Stmt(Stmt::Decl(Decl::Var(var_decl)))
+                | ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                    decl: Decl::Var(var_decl),
+                    ..
+                })) => var_decl,
+                _ => continue,
+            };
+            if var_decl.kind != VarDeclKind::Const || var_decl.declare {
+                continue;
+            }
+            self.statements.push(span);
+            for declarator in &mut var_decl.decls {
+                self.defer_constant(declarator);
+            }
+            self.statements.pop();
+        }
+    }
+
+    /// 객체/배열 리터럴로 초기화한 상수 하나를 지연 번역하고 값 모양을 기록
+    /// 값 전체를 여기서 방문하므로 본 순회에서는 이 선언을 건너뜀
+    fn defer_constant(&mut self, declarator: &mut VarDeclarator) {
+        let Pat::Ident(binding) = &declarator.name else {
+            return;
+        };
+        let name = binding.id.sym.to_string();
+        let Some(init) = declarator.init.as_deref_mut() else {
+            return;
+        };
+        let init = unwrap_constant_initializer(init);
+        if !matches!(init, Expr::Object(_) | Expr::Array(_)) || declarator.span.is_dummy() {
+            return;
+        }
+
+        let outer_binding = self.binding.replace(name.clone());
+        let shape = self.defer_value(init);
+        self.binding = outer_binding;
+        self.deferred_declarators.insert(declarator.span);
+        if shape.has_message() {
+            self.deferred_constants.insert(name, shape);
+        }
+    }
+
+    /// 상수 값 안의 문자열을 마커로 감싸고 값 모양 반환
+    /// 객체/배열이 아닌 값(함수, interpolation이 있는 템플릿 등)은 일반 규칙으로 방문
+    fn defer_value(&mut self, expr: &mut Expr) -> DeferredShape {
+        let expr = unwrap_constant_initializer(expr);
+        match expr {
+            Expr::Object(object) => {
+                let mut props = BTreeMap::new();
+                for (index, prop) in object.props.iter_mut().enumerate() {
+                    // 계산된 키({ [Status.Pending]: "대기중" })와 spread는 이름으로 접근할 수 없지만
+                    // STATUS_LABELS[s]처럼 임의의 키로 접근할 때는 함께 합쳐야 함
+                    let fallback_key = format!("[{}]", index);
+                    let PropOrSpread::Prop(prop) = prop else {
+                        prop.visit_mut_with(self);
+                        props.insert(fallback_key, DeferredShape::Other);
+                        continue;
+                    };
+                    let Prop::KeyValue(key_value) = prop.as_mut() else {
+                        prop.visit_mut_with(self);
+                        props.insert(fallback_key, DeferredShape::Other);
+                        continue;
+                    };
+                    key_value.key.visit_mut_with(self);
+                    let key = prop_key_name(&key_value.key).unwrap_or(fallback_key);
+                    let binding = self.prop_binding(&key_value.key);
+                    let outer_binding = std::mem::replace(&mut self.binding, binding);
+                    let shape = self.defer_value(&mut key_value.value);
+                    self.binding = outer_binding;
+                    props.insert(key, shape);
+                }
+                DeferredShape::Object(props)
+            }
+            Expr::Array(array) => {
+                let element = array
+                    .elems
+                    .iter_mut()
+                    .flatten()
+                    .map(|element| {
+                        if element.spread.is_some() {
+                            element.expr.visit_mut_with(self);
+                            DeferredShape::Other
+                        } else {
+                            self.defer_value(&mut element.expr)
+                        }
+                    })
+                    .reduce(DeferredShape::merge)
+                    .unwrap_or(DeferredShape::Other);
+                DeferredShape::Array(Box::new(element))
+            }
+            Expr::Lit(Lit::Str(str_lit)) => {
+                let value = str_lit.value.to_string_lossy().to_string();
+                self.defer_message(str_lit.span, &value, expr)
+            }
+            Expr::Tpl(tpl) if tpl.exprs.is_empty() => {
+                let value: String = tpl.quasis.iter().map(quasi_value).collect();
+                self.defer_message(tpl.span, &value, expr)
+            }
+            // 재실행: 이미 마커로 감싼 값
+            Expr::Call(CallExpr {
+                callee: Callee::Expr(callee),
+                ..
+            }) if self.is_deferred_marker(callee) => DeferredShape::Message,
+            _ => {
+                expr.visit_mut_with(self);
+                DeferredShape::Other
+            }
+        }
+    }
+
+    /// 번역 대상 문자열이면 마커 호출로 교체: "대기중" → tKey("대기중")
+    /// 컴포넌트 밖이지만 t를 부르지 않으므로 NoComponentScope로 보고하지 않음
+    fn defer_message(&mut self, span: Span, value: &str, expr: &mut Expr) -> DeferredShape {
+        if let Some(reason) = self.skip_reason(span) {
+            if self.is_source_text(value) {
+                self.report_skip(value, reason, span);
+            }
+            return DeferredShape::Other;
+        }
+        match self.match_text(value, TextContext::Code) {
+            TextMatch::Translate => {}
+            TextMatch::Borderline => {
+                self.report_skip(value.trim(), SkipReason::LowConfidence, span);
+                return DeferredShape::Other;
+            }
+            TextMatch::Ignore => return DeferredShape::Other,
+        }
+
+        self.mark_modified();
+        self.deferring = true;
+        *expr = self.create_t_call(span, value, None);
+        self.deferring = false;
+        DeferredShape::Message
+    }
+
+    /// 표현식이 지연 번역 상수의 어느 부분을 가리키는지
+    /// STATUS_LABELS[s], OPTIONS[0].label, option.label(map 콜백 인자), Object.values(STATUS_LABELS)
+    fn deferred_shape(&self, expr: &Expr) -> Option<DeferredShape> {
+        match expr {
+            Expr::Ident(ident) => self
+                .deferred_aliases
+                .iter()
+                .rev()
+                .find(|(name, _)| *name == *ident.sym)
+                .map(|(_, shape)| shape.clone())
+                .or_else(|| self.deferred_constants.get(&*ident.sym).cloned()),
+            Expr::Member(member) => self.deferred_shape(&member.obj)?.member(&member.prop),
+            Expr::OptChain(OptChainExpr { base, .. }) => match base.as_ref() {
+                OptChainBase::Member(member) => self.deferred_shape(&member.obj)?.member(&member.prop),
+                OptChainBase::Call(_) => None,
+            },
+            Expr::Paren(paren) => self.deferred_shape(&paren.expr),
+            Expr::Call(CallExpr {
+                callee: Callee::Expr(callee),
+                args,
+                ..
+            }) => {
+                let Expr::Member(MemberExpr {
+                    obj,
+                    prop: MemberProp::Ident(method),
+                    ..
+                }) = callee.as_ref()
+                else {
+                    return None;
+                };
+                let (object, values) = StringConstants::OBJECT_VALUES;
+                if !matches!(obj.as_ref(), Expr::Ident(ident) if &*ident.sym == object) || &*method.sym != values {
+                    return None;
+                }
+                match self.deferred_shape(&args.first()?.expr)? {
+                    DeferredShape::Object(props) => props
+                        .into_values()
+                        .reduce(DeferredShape::merge)
+                        .map(|element| DeferredShape::Array(Box::new(element))),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// 컴포넌트 안에서 지연 번역 상수의 메시지를 가리키는 표현식인지
+    fn is_deferred_usage(&self, expr: &Expr) -> bool {
+        !self.components.is_empty()
+            && self.skip_reason(expr.span()).is_none()
+            && self.deferred_shape(expr) == Some(DeferredShape::Message)
+    }
+
+    /// JSX 메시지로 합쳐질 수 있는 지연 번역 상수 사용처: {item.title} 바로가기
+    /// 먼저 감싸면 메시지가 끊기므로 flush_jsx_message_run에서 감쌈
+    fn is_deferred_usage_child(&self, child: &JSXElementChild) -> bool {
+        match child {
+            JSXElementChild::JSXExprContainer(JSXExprContainer {
+                expr: JSXExpr::Expr(expr),
+                ..
+            }) => is_message_child(child) && self.is_deferred_usage(expr),
+            _ => false,
+        }
+    }
+
+    /// 컴포넌트 안에서 지연 번역 상수의 메시지를 가리키는 표현식을 번역 함수로 감쌈
+    /// {STATUS_LABELS[s]} → {t(STATUS_LABELS[s])}
+    fn wrap_deferred_usage(&mut self, expr: &mut Expr) -> bool {
+        if !self.is_deferred_usage(expr) {
+            return false;
+        }
+
+        self.mark_modified();
+        let value = std::mem::replace(expr, Expr::Invalid(Invalid { span: DUMMY_SP }));
+        *expr = Expr::Call(CallExpr {
+            span: value.span(),
+            callee: Callee::Expr(Box::new(self.translation_callee())),
             args: vec![ExprOrSpread {
                 spread: None,
-                expr: Box::new(Expr::Lit(Lit::Str(Str {
-                    span: n.span,
-                    value: trimmed.into(),
-                    raw: None,
-                }))),
+                expr: Box::new(value),
             }],
             type_args: None,
             ctxt: Default::default(),
         });
-        
-        // JSXExpressionContainer로 감싸기
-        // JSXText를 JSXExpr로 변환
-        // 현재는 플래그만 설정 (실제 교체는 상위 레벨에서 처리 필요)
+        true
     }
-}
 
-/// Module을 변환하고 결과 반환
+    /// 배열 상수 순회 콜백의 인자가 가리키는 원소 모양
+    /// OPTIONS.map((option) => ...) → option, OPTIONS.map(({ label }) => ...) → label
+    fn callback_aliases(&self, call: &CallExpr) -> Vec<(String, DeferredShape)> {
+        let Callee::Expr(callee) = &call.callee else {
+            return Vec::new();
+        };
+        let Expr::Member(MemberExpr {
+            obj,
+            prop: MemberProp::Ident(method),
+            ..
+        }) = callee.as_ref()
+        else {
+            return Vec::new();
+        };
+        if !StringConstants::ARRAY_ITERATION_METHODS.contains(&&*method.sym) {
+            return Vec::new();
+        }
+        let Some(DeferredShape::Array(element)) = self.deferred_shape(obj) else {
+            return Vec::new();
+        };
+
+        let param = match call.args.first().map(|arg| arg.expr.as_ref()) {
+            Some(Expr::Arrow(arrow)) => arrow.params.first(),
+            Some(Expr::Fn(fn_expr)) => fn_expr.function.params.first().map(|param| &param.pat),
+            _ => None,
+        };
+        param
+            .map(|param| pattern_aliases(param, &element))
+            .unwrap_or_default()
+    }
+
+    /// 객체 프로퍼티 값이 할당되는 이름: const labels = { save: "저장" } → labels.save (semantic 키)
+    fn prop_binding(&self, key: &PropName) -> Option<String> {
+        let key = match key {
+            PropName::Ident(ident) => Some(ident.sym.to_string()),
+            PropName::Str(str_lit) => Some(slugify(&str_lit.value.to_string_lossy())),
+            _ => None,
+        };
+        match (key, &self.binding) {
+            (Some(key), Some(outer)) => {
+                Some(format!("{}{}{}", outer, KeyStrategies::SEGMENT_SEPARATOR, key))
+            }
+            (key, outer) => key.or_else(|| outer.clone()),
+        }
+    }
+
+    /// 텍스트/표현식 조각들을 하나의 interpolation t() 호출로 변환
+    /// 템플릿 리터럴, JSX children, 문자열 연결이 모두 같은 형식을 만들도록 공유
+    ///
+    /// 예: ["안녕 ", name, "님"] → t("안녕 {{name}}님", { name })
+    ///     [price.toLocaleString(), "원"] → t("{{price, currency(KRW)}}", { price })
+    fn create_interpolated_t_call(&mut self, span: Span, parts: Vec<MessagePart>) -> Expr {
+        // i18next 형식: `안녕 ${name}` → "안녕 {{name}}"
+        let mut i18next_string = String::new();
+        let mut interpolation_vars = Vec::new();
+        let mut names = InterpolationNames::default();
+        let parts = self.apply_value_formats(parts);
+        let parts = self.apply_josa(parts);
+
+        // 개수 표현식은 i18next가 _one/_other 키를 고를 수 있도록 count로 고정
+        if let Some(expr) = self.find_counter(&parts).and_then(|index| parts[index].value()) {
+            names.reserve(Pluralization::COUNT_VARIABLE, expr);
+        }
+
+        for part in parts {
+            let (expr, format) = match part {
+                MessagePart::Text(text) => {
+                    i18next_string.push_str(&text);
+                    continue;
+                }
+                MessagePart::Expr(expr) => (expr, None),
+                MessagePart::Formatted(expr, format) => (expr, Some(format)),
+            };
+            let (var_name, is_new) = names.assign(self.extract_var_name(&expr), &expr);
+            // 지연 번역 상수 값은 번역해서 넘김: { title: t(item.title) }
+            let mut expr = expr;
+            self.wrap_deferred_usage(&mut expr);
+
+            i18next_string.push_str(StringConstants::INTERPOLATION_START);
+            i18next_string.push_str(&var_name);
+            if let Some(format) = format {
+                i18next_string.push_str(Formatting::FORMAT_SEPARATOR);
+                i18next_string.push_str(&format);
+            }
+            i18next_string.push_str(StringConstants::INTERPOLATION_END);
+
+            if is_new {
+                interpolation_vars.push(create_interpolation_prop(&var_name, expr));
+            }
+        }
+
+        let options = if interpolation_vars.is_empty() {
+            None
+        } else {
+            Some(ObjectLit {
+                span: DUMMY_SP,
+                props: interpolation_vars,
+            })
+        };
+
+        self.create_t_call(span, &i18next_string, options)
+    }
+
+    /// `+` 문자열 연결 체인을 하나의 t() 호출로 변환
+    /// 한국어 문자열 조각이 하나라도 있어야 변환하며, 없으면 None 반환
+    ///
+    /// 조각마다 t()를 따로 만들면 "총 ", "건" 같은 비문 키가 생기므로
+    /// 템플릿 리터럴과 같은 interpolation 경로로 합침
+    fn concat_to_t_call(&mut self, span: Span, bin: &BinExpr) -> Option<Expr> {
+        let operands = flatten_concat(bin);
+
+        // 변환하지 않으면 피연산자 문자열이 따로 방문되므로 여기서는 보고하지 않음
+        let text: String = operands
+            .iter()
+            .map(|operand| match operand {
+                Expr::Lit(Lit::Str(str_lit)) => str_lit.value.to_string_lossy().to_string(),
+                Expr::Tpl(tpl) => tpl.quasis.iter().map(quasi_value).collect(),
+                _ => String::new(),
+            })
+            .collect();
+        if self.match_text(&text, TextContext::Code) != TextMatch::Translate
+            || self.outside_component_scope()
+        {
+            return None;
+        }
+
+        let mut parts = Vec::with_capacity(operands.len());
+        for operand in operands {
+            match operand {
+                Expr::Lit(Lit::Str(str_lit)) => {
+                    push_message_text(&mut parts, &str_lit.value.to_string_lossy());
+                }
+                Expr::Tpl(tpl) => {
+                    let mut tpl = tpl.clone();
+                    tpl.visit_mut_children_with(self);
+                    for (index, quasi) in tpl.quasis.iter().enumerate() {
+                        push_message_text(&mut parts, &quasi_value(quasi));
+                        if let Some(tpl_expr) = tpl.exprs.get(index) {
+                            parts.push(MessagePart::Expr(tpl_expr.clone()));
+                        }
+                    }
+                }
+                _ => {
+                    // 피연산자 내부의 한국어(함수 인자 등)는 따로 변환
+                    let mut operand = Box::new(operand.clone());
+                    operand.visit_mut_with(self);
+                    parts.push(MessagePart::Expr(operand));
+                }
+            }
+        }
+
+        Some(self.create_interpolated_t_call(span, parts))
+    }
+
+    /// JSX children 중 연속된 텍스트/표현식 묶음을 하나의 메시지로 변환
+    /// 예: {count}개의 항목이 있습니다 → {t("{{count}}개의 항목이 있습니다", { count })}
+    ///
+    /// 한국어 텍스트가 없으면 원래 children을 그대로 유지
+    fn flush_jsx_message_run(
+        &mut self,
+        run: &mut Vec<JSXElementChild>,
+        new_children: &mut Vec<JSXElementChild>,
+    ) {
+        let run_children = std::mem::take(run);
+
+        let mut parts: Vec<MessagePart> = Vec::with_capacity(run_children.len());
+        let mut span = None;
+        for child in &run_children {
+            match child {
+                JSXElementChild::JSXText(jsx_text) => {
+                    span.get_or_insert(jsx_text.span);
+                    push_message_text(&mut parts, &clean_jsx_text(&jsx_text.value));
+                }
+                JSXElementChild::JSXExprContainer(JSXExprContainer {
+                    expr: JSXExpr::Expr(expr),
+                    ..
+                }) => match expr.as_ref() {
+                    Expr::Lit(Lit::Str(str_lit)) => {
+                        push_message_text(&mut parts, &str_lit.value.to_string_lossy());
+                    }
+                    _ => parts.push(MessagePart::Expr(expr.clone())),
+                },
+                _ => {}
+            }
+        }
+
+        let text: String = parts
+            .iter()
+            .filter_map(|part| match part {
+                MessagePart::Text(text) => Some(text.as_str()),
+                _ => None,
+            })
+            .collect();
+        if !self.should_translate(&text, TextContext::JsxText, span.unwrap_or(DUMMY_SP)) {
+            for mut child in run_children {
+                if let JSXElementChild::JSXExprContainer(JSXExprContainer {
+                    expr: JSXExpr::Expr(expr),
+                    ..
+                }) = &mut child
+                {
+                    self.wrap_deferred_usage(expr);
+                }
+                new_children.push(child);
+            }
+            return;
+        }
+
+        self.mark_modified();
+
+        // React가 렌더링하는 앞뒤 공백은 t() 바깥에 {" "}로 유지
+        let mut leading = String::new();
+        let mut trailing = String::new();
+        if let Some(MessagePart::Text(first)) = parts.first_mut() {
+            let (lead, _, _) = split_jsx_whitespace(first);
+            leading = lead.to_string();
+            first.drain(..leading.len());
+        }
+        if let Some(MessagePart::Text(last)) = parts.last_mut() {
+            let (_, _, trail) = split_jsx_whitespace(last);
+            trailing = trail.to_string();
+            last.truncate(last.len() - trailing.len());
+        }
+        parts.retain(|part| !matches!(part, MessagePart::Text(text) if text.is_empty()));
+
+        if !leading.is_empty() {
+            new_children.push(create_jsx_string_child(&leading));
+        }
+        let t_call = self.create_interpolated_t_call(DUMMY_SP, parts);
+        new_children.push(JSXElementChild::JSXExprContainer(JSXExprContainer {
+            span: span.unwrap_or(DUMMY_SP),
+            expr: JSXExpr::Expr(Box::new(t_call)),
+        }));
+        if !trailing.is_empty() {
+            new_children.push(create_jsx_string_child(&trailing));
+        }
+    }
+
+    /// 리치 텍스트 children 수집
+    /// 한국어 텍스트 + 인라인 엘리먼트 + 단순 표현식이 섞인 children을 하나의 메시지로 만듦
+    ///
+    /// 예: 약관에 <a href="/terms">동의</a>합니다
+    ///   → message: "약관에 <0>동의</0>합니다", components: [<a href="/terms" />]
+    ///
+    /// 인라인 엘리먼트가 없거나, 한국어가 없거나, 단순하지 않은 표현식/블록 엘리먼트가 있으면 None
+    fn collect_rich_text(&self, children: &[JSXElementChild]) -> Option<RichTextMessage> {
+        let mut rich_text = RichTextMessage::default();
+        // 태그 바깥에 텍스트가 있어야 문장으로 취급 (<div><span>안녕</span></div>는 제외)
+        let mut has_outer_text = false;
+
+        for child in children {
+            match child {
+                JSXElementChild::JSXElement(element) => {
+                    if !is_inline_element(element) {
+                        return None;
+                    }
+                    let index = rich_text.components.len();
+                    // 태그 안쪽도 같은 메시지이므로 변수명 배정 상태를 공유
+                    let mut inner = RichTextMessage {
+                        names: std::mem::take(&mut rich_text.names),
+                        ..Default::default()
+                    };
+                    for inner_child in &element.children {
+                        if !self.push_rich_text_part(inner_child, &mut inner) {
+                            return None;
+                        }
+                    }
+                    rich_text.message.push_str(&format!("<{index}>{}</{index}>", inner.message));
+                    rich_text.values.extend(inner.values);
+                    rich_text.names = inner.names;
+                    rich_text.components.push(strip_jsx_children(element));
+                }
+                _ => {
+                    let before_len = rich_text.message.len();
+                    if !self.push_rich_text_part(child, &mut rich_text) {
+                        return None;
+                    }
+                    has_outer_text |= !rich_text.message[before_len..].trim().is_empty();
+                }
+            }
+        }
+
+        let has_source_text = self.match_text(&rich_text.message, TextContext::JsxText)
+            == TextMatch::Translate
+            && !self.outside_component_scope();
+        if rich_text.components.is_empty() || !has_outer_text || !has_source_text {
+            return None;
+        }
+        Some(rich_text)
+    }
+
+    /// 텍스트/표현식 child 하나를 리치 텍스트 메시지에 추가
+    /// 지원하지 않는 child(엘리먼트, 복잡한 표현식 등)면 false
+    fn push_rich_text_part(&self, child: &JSXElementChild, rich_text: &mut RichTextMessage) -> bool {
+        match child {
+            JSXElementChild::JSXText(jsx_text) => {
+                rich_text.message.push_str(&clean_jsx_text(&jsx_text.value));
+                true
+            }
+            JSXElementChild::JSXExprContainer(container) => match &container.expr {
+                JSXExpr::JSXEmptyExpr(_) => true,
+                JSXExpr::Expr(expr) => match expr.as_ref() {
+                    Expr::Lit(Lit::Str(str_lit)) => {
+                        rich_text.message.push_str(&str_lit.value.to_string_lossy());
+                        true
+                    }
+                    Expr::Ident(_) | Expr::Member(_) => {
+                        let (var_name, is_new) =
+                            rich_text.names.assign(self.extract_var_name(expr), expr);
+                        rich_text.message.push_str(StringConstants::INTERPOLATION_START);
+                        rich_text.message.push_str(&var_name);
+                        rich_text.message.push_str(StringConstants::INTERPOLATION_END);
+                        if is_new {
+                            rich_text.values.push(create_interpolation_prop(&var_name, expr.clone()));
+                        }
+                        true
+                    }
+                    _ => false,
+                },
+            },
+            _ => false,
+        }
+    }
+
+    /// 리치 텍스트 메시지를 JSX child로 변환
+    /// "trans": <Trans i18nKey="..." components={[...]} values={{ ... }} />
+    /// "indexed": {t("...", { components: [...], ... })}
+    fn create_rich_text_child(&mut self, rich_text: RichTextMessage) -> JSXElementChild {
+        let RichTextMessage {
+            message,
+            mut components,
+            values,
+            ..
+        } = rich_text;
+
+        // 컴포넌트로 빠진 엘리먼트의 속성(title="..." 등)도 번역
+        for component in components.iter_mut() {
+            component.visit_mut_with(self);
+        }
+
+        let components_expr = Expr::Array(ArrayLit {
+            span: DUMMY_SP,
+            elems: components
+                .into_iter()
+                .map(|component| {
+                    Some(ExprOrSpread {
+                        spread: None,
+                        expr: Box::new(Expr::JSXElement(Box::new(component))),
+                    })
+                })
+                .collect(),
+        });
+
+        if self.config.rich_text.as_deref() == Some(StringConstants::RICH_TEXT_INDEXED) {
+            let mut props = vec![create_interpolation_prop(
+                StringConstants::TRANS_COMPONENTS,
+                Box::new(components_expr),
+            )];
+            props.extend(values);
+            let t_call = self.create_t_call(DUMMY_SP, &message, Some(ObjectLit { span: DUMMY_SP, props }));
+            return JSXElementChild::JSXExprContainer(JSXExprContainer {
+                span: DUMMY_SP,
+                expr: JSXExpr::Expr(Box::new(t_call)),
+            });
+        }
+
+        self.uses_trans_component = true;
+
+        let key = self.translation_key(&message);
+        let mut attrs = vec![
+            create_jsx_attr(
+                StringConstants::TRANS_I18N_KEY,
+                JSXAttrValue::Str(Str {
+                    span: DUMMY_SP,
+                    value: key.as_str().into(),
+                    raw: None,
+                }),
+            ),
+            create_jsx_attr(
+                StringConstants::TRANS_COMPONENTS,
+                JSXAttrValue::JSXExprContainer(JSXExprContainer {
+                    span: DUMMY_SP,
+                    expr: JSXExpr::Expr(Box::new(components_expr)),
+                }),
+            ),
+        ];
+        if key != message {
+            attrs.push(create_jsx_attr(
+                StringConstants::TRANS_DEFAULTS,
+                JSXAttrValue::Str(Str {
+                    span: DUMMY_SP,
+                    value: message.as_str().into(),
+                    raw: None,
+                }),
+            ));
+        }
+        if !values.is_empty() {
+            attrs.push(create_jsx_attr(
+                StringConstants::TRANS_VALUES,
+                JSXAttrValue::JSXExprContainer(JSXExprContainer {
+                    span: DUMMY_SP,
+                    expr: JSXExpr::Expr(Box::new(Expr::Object(ObjectLit {
+                        span: DUMMY_SP,
+                        props: values,
+                    }))),
+                }),
+            ));
+        }
+
+        JSXElementChild::JSXElement(Box::new(JSXElement {
+            span: DUMMY_SP,
+            opening: JSXOpeningElement {
+                name: JSXElementName::Ident(Ident {
+                    span: DUMMY_SP,
+                    sym: StringConstants::TRANS_COMPONENT.into(),
+                    optional: false,
+                    ctxt: Default::default(),
+                }),
+                span: DUMMY_SP,
+                attrs,
+                self_closing: true,
+                type_args: None,
+            },
+            children: vec![],
+            closing: None,
+        }))
+    }
+
+    /// 표현식에서 변수명 추출
+    /// Identifier: name 그대로 사용
+    /// MemberExpression: user.name → user_name, user?.profile.name → user_profile_name
+    /// 계산된 멤버: items[0] → items_0, map["key"] → map_key
+    /// CallExpression: formatDate(d) → formatDate, date.toISOString() → date_toISOString
+    /// 기타: None (InterpolationNames에서 expr0, expr1 등으로 처리)
+    fn extract_var_name(&self, expr: &Expr) -> Option<String> {
+        match expr {
+            Expr::Ident(ident) => Some(ident.sym.to_string()),
+            Expr::Member(member) => self.extract_member_var_name(member),
+            Expr::OptChain(opt_chain) => match opt_chain.base.as_ref() {
+                OptChainBase::Member(member) => self.extract_member_var_name(member),
+                OptChainBase::Call(call) => self.extract_var_name(&call.callee),
+            },
+            Expr::Call(CallExpr {
+                callee: Callee::Expr(callee),
+                ..
+            }) => self.extract_var_name(callee),
+            Expr::Paren(paren) => self.extract_var_name(&paren.expr),
+            Expr::TsAs(ts_as) => self.extract_var_name(&ts_as.expr),
+            Expr::TsNonNull(non_null) => self.extract_var_name(&non_null.expr),
+            _ => None,
+        }
+    }
+
+    /// 멤버 표현식 변수명: object 부분 + property 부분을 "_"로 연결
+    /// this.props.name처럼 this로 시작하면 this는 생략
+    fn extract_member_var_name(&self, member: &MemberExpr) -> Option<String> {
+        let prop = match &member.prop {
+            MemberProp::Ident(prop_ident) => prop_ident.sym.to_string(),
+            MemberProp::PrivateName(private) => private.name.to_string(),
+            MemberProp::Computed(computed) => match computed.expr.as_ref() {
+                Expr::Lit(Lit::Str(str_lit)) => str_lit.value.to_string_lossy().to_string(),
+                Expr::Lit(Lit::Num(num)) => num.value.to_string(),
+                Expr::Ident(ident) => ident.sym.to_string(),
+                _ => return None,
+            },
+        };
+
+        if matches!(member.obj.as_ref(), Expr::This(_)) {
+            return Some(sanitize_var_name(&prop));
+        }
+
+        let object = self.extract_var_name(&member.obj)?;
+        Some(sanitize_var_name(&format!(
+            "{}{}{}",
+            object,
+            StringConstants::MEMBER_SEPARATOR,
+            prop
+        )))
+    }
+
+    /// 메시지에서 복수형 기준이 되는 개수 표현식의 위치를 찾음
+    /// 1. 바로 뒤 텍스트가 단위 명사로 시작: `${n}명 참여`, "총 " + total + "건"
+    /// 2. 변수/프로퍼티 이름이 개수 이름: `${count} new`, `${items.length} items`
+    ///
+    /// 단위 명사로 찾은 표현식을 우선하며, 없으면 None
+    /// 옮기지 못해 그대로 둔 포맷 호출(n.toLocaleString(...))은 문자열이므로 count가 될 수 없음
+    fn find_counter(&self, parts: &[MessagePart]) -> Option<usize> {
+        let followed_by_suffix = |index: usize| match parts.get(index + 1) {
+            Some(MessagePart::Text(text)) => self.starts_with_counter_suffix(text),
+            _ => false,
+        };
+        let expr_indices = || {
+            parts
+                .iter()
+                .enumerate()
+                .filter(|(_, part)| part.value().is_some_and(|expr| format_call(expr).is_none()))
+                .map(|(index, _)| index)
+        };
+
+        expr_indices().find(|&index| followed_by_suffix(index)).or_else(|| {
+            expr_indices()
+                .find(|&index| parts[index].value().is_some_and(|expr| self.is_counter_name(expr)))
+        })
+    }
+
+    /// 텍스트가 단위 명사로 시작하는지 확인 (숫자와 단위 사이 공백 허용: "3 개")
+    fn starts_with_counter_suffix(&self, text: &str) -> bool {
+        let text = text.trim_start();
+        match &self.config.counter_suffixes {
+            Some(suffixes) => suffixes.iter().any(|suffix| text.starts_with(suffix.as_str())),
+            None => Pluralization::COUNTER_SUFFIXES
+                .iter()
+                .any(|suffix| text.starts_with(suffix)),
+        }
+    }
+
+    /// 표현식의 마지막 이름(count, items.length의 length)이 개수 이름인지 확인
+    fn is_counter_name(&self, expr: &Expr) -> bool {
+        let name = match expr {
+            Expr::Ident(ident) => ident.sym.to_string(),
+            Expr::Member(MemberExpr {
+                prop: MemberProp::Ident(prop),
+                ..
+            }) => prop.sym.to_string(),
+            Expr::Paren(paren) => return self.is_counter_name(&paren.expr),
+            Expr::TsAs(ts_as) => return self.is_counter_name(&ts_as.expr),
+            Expr::TsNonNull(non_null) => return self.is_counter_name(&non_null.expr),
+            _ => return false,
+        };
+
+        match &self.config.counter_names {
+            Some(names) => names.contains(&name),
+            None => Pluralization::COUNTER_NAMES.contains(&name.as_str()),
+        }
+    }
+
+    /// 한국어 형식으로 고정된 숫자/통화/날짜 표현식을 i18next 포맷 placeholder로 변환
+    /// - 같은 Date의 getter 묶음: `${d.getMonth() + 1}월 ${d.getDate()}일` → {{d, datetime}}
+    /// - toLocaleString / Intl.NumberFormat: {{price, number}}
+    /// - toLocaleDateString / Intl.DateTimeFormat: {{date, datetime}}
+    /// - 숫자 뒤 통화 단위(원, 달러): {{price, currency(KRW)}} (단위는 포맷이 출력하므로 제거)
+    ///
+    /// 옵션을 옮길 수 없는 포맷 호출이나 getter 하나만 쓴 날짜는 그대로 두고 UnsupportedFormat으로 보고
+    fn apply_value_formats(&mut self, mut parts: Vec<MessagePart>) -> Vec<MessagePart> {
+        let mut formatted = Vec::with_capacity(parts.len());
+        let mut index = 0;
+
+        while index < parts.len() {
+            let part = std::mem::replace(&mut parts[index], MessagePart::Text(String::new()));
+            let expr = match part {
+                MessagePart::Expr(expr) => expr,
+                part => {
+                    formatted.push(part);
+                    index += 1;
+                    continue;
+                }
+            };
+
+            if let Some(date) = date_getter_object(&expr) {
+                let last = date_getter_run_end(&parts, index, date);
+                if last == index {
+                    self.report_unsupported_format(&expr);
+                    formatted.push(MessagePart::Expr(expr));
+                } else {
+                    formatted.push(MessagePart::Formatted(
+                        Box::new(date.clone()),
+                        Formatting::DATETIME.to_string(),
+                    ));
+                    // 마지막 getter 뒤의 단위("일")는 datetime 포맷에 포함
+                    if let Some(MessagePart::Text(text)) = parts.get_mut(last + 1) {
+                        if text.starts_with(Formatting::DATE_UNITS) {
+                            text.drain(..text.chars().next().map_or(0, char::len_utf8));
+                        }
+                    }
+                }
+                index = last + 1;
+                continue;
+            }
+
+            let (value, format) = match format_call(&expr) {
+                Some(FormatCall::Supported(value, format)) => (value, Some(format)),
+                Some(FormatCall::Unsupported) => {
+                    self.report_unsupported_format(&expr);
+                    formatted.push(MessagePart::Expr(expr));
+                    index += 1;
+                    continue;
+                }
+                None => (expr, None),
+            };
+
+            let currency = match (parts.get_mut(index + 1), format.as_deref()) {
+                (Some(MessagePart::Text(text)), None | Some(Formatting::NUMBER)) => {
+                    strip_currency_suffix(text)
+                }
+                _ => None,
+            };
+            formatted.push(match (currency, format) {
+                (Some(code), _) => MessagePart::Formatted(
+                    value,
+                    format!("{}({})", Formatting::CURRENCY, code),
+                ),
+                (None, Some(format)) => MessagePart::Formatted(value, format),
+                (None, None) => MessagePart::Expr(value),
+            });
+            index += 1;
+        }
+
+        formatted
+    }
+
+    /// interpolation 바로 뒤에 붙은 조사 처리
+    /// 값의 받침 유무에 따라 을/를, 이/가 등이 달라지므로 원문에 고정된 조사는 틀릴 수 있음
+    ///
+    /// - josa_hints: `${name}을 삭제` → {{name, josa(을/를)}} 삭제
+    /// - 그 외: 조사는 그대로 두고 JosaParticle로 보고
+    ///
+    /// 포맷을 붙인 값({{price, currency(KRW)}}이)은 포맷 결과에 따라 조사가 달라지므로
+    /// josa_hints와 관계없이 조사를 그대로 두고 보고
+    fn apply_josa(&mut self, mut parts: Vec<MessagePart>) -> Vec<MessagePart> {
+        for index in 0..parts.len().saturating_sub(1) {
+            let (expr, formatted, text) = match (&parts[index], &parts[index + 1]) {
+                (MessagePart::Expr(expr), MessagePart::Text(text)) => (expr, false, text),
+                (MessagePart::Formatted(expr, _), MessagePart::Text(text)) => (expr, true, text),
+                _ => continue,
+            };
+            let Some((particle, pair)) = leading_josa(text) else {
+                continue;
+            };
+
+            if !self.config.josa_hints || formatted {
+                let span = expr.span();
+                let source = source_of(&self.source_code, span).unwrap_or_default();
+                let value = format!("{}{}", source, particle);
+                self.report_warning(&value, WarningKind::JosaParticle, span);
+                continue;
+            }
+
+            if let MessagePart::Text(text) = &mut parts[index + 1] {
+                text.drain(..particle.len());
+            }
+            let part = std::mem::replace(&mut parts[index], MessagePart::Text(String::new()));
+            if let MessagePart::Expr(expr) = part {
+                parts[index] = MessagePart::Formatted(expr, format!("{}({})", Josa::FORMAT, pair));
+            }
+        }
+
+        parts
+    }
+
+    /// i18next 포맷으로 옮길 수 없는 포맷 표현식 보고
+    fn report_unsupported_format(&mut self, expr: &Expr) {
+        let span = expr.span();
+        let value = source_of(&self.source_code, span).unwrap_or_default().to_string();
+        self.report_warning(&value, WarningKind::UnsupportedFormat, span);
+    }
+}
+
+/// 메시지 하나 안에서 interpolation 변수명을 배정
+/// - 같은 표현식이 반복되면 같은 변수명을 재사용 (객체 프로퍼티 중복 없음)
+/// - 다른 표현식이 같은 이름을 가지면 숫자 접미사로 구분: name, name2, name3
+/// - 이름을 만들 수 없는 표현식은 expr0, expr1 순서로 배정
+#[derive(Default)]
+struct InterpolationNames {
+    assigned: Vec<(String, Box<Expr>)>,
+    /// 미리 이름을 정해 둔 표현식 (복수형 count), 처음 나올 때 assigned로 옮김
+    reserved: Option<(String, Box<Expr>)>,
+    expr_count: usize,
+}
+
+impl InterpolationNames {
+    /// 표현식에 변수명을 미리 배정 (다른 표현식이 같은 이름을 쓰면 숫자 접미사로 밀려남)
+    fn reserve(&mut self, name: &str, expr: &Expr) {
+        self.reserved = Some((name.to_string(), Box::new(expr.clone())));
+    }
+
+    /// 표현식의 변수명과 새로 배정되었는지 여부를 반환
+    /// 새로 배정된 경우에만 interpolation 객체에 프로퍼티를 추가해야 함
+    fn assign(&mut self, base_name: Option<String>, expr: &Expr) -> (String, bool) {
+        if let Some((name, reserved_expr)) = self.reserved.take() {
+            if reserved_expr.as_ref().eq_ignore_span(expr) {
+                self.assigned.push((name.clone(), reserved_expr));
+                return (name, true);
+            }
+            self.reserved = Some((name, reserved_expr));
+        }
+
+        if let Some((name, _)) = self
+            .assigned
+            .iter()
+            .find(|(_, assigned_expr)| assigned_expr.as_ref().eq_ignore_span(expr))
+        {
+            return (name.clone(), false);
+        }
+
+        let name = match base_name {
+            Some(base) => {
+                let mut candidate = base.clone();
+                let mut suffix = 2;
+                while self.is_taken(&candidate) {
+                    candidate = format!("{}{}", base, suffix);
+                    suffix += 1;
+                }
+                candidate
+            }
+            None => loop {
+                let candidate = format!("{}{}", StringConstants::EXPR_PREFIX, self.expr_count);
+                self.expr_count += 1;
+                if !self.is_taken(&candidate) {
+                    break candidate;
+                }
+            },
+        };
+
+        self.assigned.push((name.clone(), Box::new(expr.clone())));
+        (name, true)
+    }
+
+    fn is_taken(&self, name: &str) -> bool {
+        self.assigned.iter().any(|(assigned, _)| assigned == name)
+            || self.reserved.as_ref().is_some_and(|(reserved, _)| reserved == name)
+    }
+}
+
+/// 변수명으로 쓸 수 없는 문자를 "_"로 치환
+/// 숫자로 시작하면 앞에 "_"를 붙임
+fn sanitize_var_name(name: &str) -> String {
+    let sanitized: String = name
+        .chars()
+        .map(|c| if c.is_alphanumeric() || c == '_' || c == '$' { c } else { '_' })
+        .collect();
+
+    if sanitized.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{}", sanitized)
+    } else {
+        sanitized
+    }
+}
+
+impl VisitMut for TranslationTransformer {
+    /// FunctionDeclaration 변환
+    /// TypeScript 버전과 동일한 로직:
+    /// 1. React 컴포넌트인지 확인
+    /// 2. 함수 body를 변환
+    /// 3. 변환된 경우 함수 이름 저장
+    fn visit_mut_fn_decl(&mut self, func: &mut FnDecl) {
+        // React 컴포넌트인지 확인
+        let name = func.ident.sym.to_string();
+        if crate::ast_helpers::is_react_component(&name) {
+            // 함수 body 변환 (자식 노드 방문으로 자동 처리됨)
+            self.visit_component(name, func);
+            return;
+        }
+        // React 컴포넌트가 아니면 자식 노드만 방문
+        func.visit_mut_children_with(self);
+    }
+
+    /// ArrowFunctionExpression 변환
+    /// TypeScript 버전과 동일한 로직:
+    /// 1. 변수 선언의 일부인지 확인
+    /// 2. React 컴포넌트인지 확인
+    /// 3. 함수 body를 변환
+    /// 4. 변환된 경우 함수 이름 저장
+    fn visit_mut_expr(&mut self, expr: &mut Expr) {
+        // ArrowFunctionExpression 처리
+        if let Expr::Arrow(arrow) = expr {
+            // TODO: 부모 노드 확인하여 변수 선언인지 확인
+            // 현재는 일단 모든 ArrowFunction을 처리
+            let before_modified = self.was_modified;
+            arrow.visit_mut_children_with(self);
+            // 변환되었으면 기록 (이름은 나중에 부모에서 확인)
+            if self.was_modified && !before_modified {
+                // TODO: 변수 이름 추출
+            }
+            return;
+        }
+
+        // i18n-ignore, t() 인자, import(), enum 멤버 등 스킵 문맥 안에서는 변환하지 않고 기록만 함
+        // 노드에 붙은 i18n-ignore는 하위 노드 전체에 적용되도록 문맥에 쌓음
+        if let Some(reason) = self.skip_reason(expr.span()) {
+            self.parents.push(reason);
+            match expr {
+                Expr::Lit(Lit::Str(str_lit)) => {
+                    let value = str_lit.value.to_string_lossy().to_string();
+                    if self.is_source_text(&value) {
+                        self.report_skip(&value, reason, str_lit.span);
+                    }
+                }
+                Expr::Tpl(tpl) => {
+                    let value: String = tpl.quasis.iter().map(quasi_value).collect();
+                    if self.is_source_text(&value) {
+                        self.report_skip(&value, reason, tpl.span);
+                    }
+                    tpl.visit_mut_children_with(self);
+                }
+                _ => expr.visit_mut_children_with(self),
+            }
+            self.parents.pop();
+            return;
+        }
+
+        // 지연 번역 상수 사용처: {STATUS_LABELS[s]} → {t(STATUS_LABELS[s])}
+        if self.wrap_deferred_usage(expr) {
+            return;
+        }
+
+        // Expression 변환 (StringLiteral을 t() 호출로 교체)
+        // TypeScript 버전과 동일한 로직:
+        // 1. StringLiteral 감지
+        // 2. 한국어 텍스트가 포함된 문자열만 처리
+        // 3. t() 함수 호출로 변환
+        if let Expr::Lit(Lit::Str(str_lit)) = expr {
+            // Wtf8Atom을 &str로 변환하여 한국어 체크
+            // 방법: to_string_lossy() 직접 사용 (최신 SWC API)
+            let str_value = str_lit.value.to_string_lossy();
+            
+            // 원본 언어 텍스트(기본: 한국어)가 포함되어 있거나 자연어 점수가 임계값 이상인지 확인
+            let span = str_lit.span;
+            if self.should_translate(&str_value, TextContext::Code, span) {
+                self.mark_modified();
+                
+                // 현재 Expression을 t() 호출로 교체
+                *expr = self.create_t_call(str_lit.span, &str_value, None);
+                // 변환 후에는 자식 노드를 방문하지 않음 (무한 재귀 방지)
+                return;
+            }
+        }
+
+        // TemplateLiteral 변환
+        // Tpl 노드는 스스로를 CallExpr로 바꿀 수 없으므로 Expr 레벨에서 교체
+        // 태그드 템플릿(styled`...`)은 Expr::TaggedTpl이라 여기서 걸리지 않음
+        if let Expr::Tpl(tpl) = expr {
+            // ${} 내부 표현식을 먼저 변환 (중첩된 한국어 문자열 처리)
+            tpl.visit_mut_children_with(self);
+
+            if let Some(t_call) = self.tpl_to_t_call(tpl) {
+                self.mark_modified();
+                *expr = t_call;
+            }
+            return;
+        }
+
+        // 문자열 연결 변환
+        // "총 " + count + "건" → t("총 {{count}}건", { count })
+        if let Expr::Bin(bin) = expr {
+            if bin.op == BinaryOp::Add {
+                let span = bin.span;
+                if let Some(t_call) = self.concat_to_t_call(span, bin) {
+                    self.mark_modified();
+                    *expr = t_call;
+                    return;
+                }
+            }
+        }
+        
+        // 재귀적으로 자식 노드 방문
+        expr.visit_mut_children_with(self);
+    }
+    
+    /// CallExpression 방문
+    /// - t("..."), i18n.t("...")의 인자는 이미 번역 대상이므로 스킵
+    /// - import("..."), require("...")의 인자는 모듈 경로이므로 스킵
+    /// - 지연 번역 배열 상수의 map/filter 등 콜백 인자는 원소를 가리키는 별칭으로 기록
+    fn visit_mut_call_expr(&mut self, call: &mut CallExpr) {
+        let reason = match &call.callee {
+            Callee::Import(_) => Some(SkipReason::ModuleSource),
+            Callee::Expr(callee) if self.is_translation_call(callee) => Some(SkipReason::TranslationCall),
+            Callee::Expr(callee) => match callee.as_ref() {
+                Expr::Ident(ident) if &*ident.sym == StringConstants::REQUIRE => Some(SkipReason::ModuleSource),
+                _ => None,
+            },
+            _ => None,
+        };
+
+        match reason {
+            Some(reason) => self.visit_with_parent(reason, call),
+            None => {
+                let depth = self.deferred_aliases.len();
+                let aliases = self.callback_aliases(call);
+                self.deferred_aliases.extend(aliases);
+                call.visit_mut_children_with(self);
+                self.deferred_aliases.truncate(depth);
+            }
+        }
+    }
+
+    /// import/export 구문은 모듈 경로만 담고 있으므로 스킵
+    /// 모듈 경로는 Expr이 아니므로 여기서 기록
+    fn visit_mut_import_decl(&mut self, import_decl: &mut ImportDecl) {
+        self.report_module_source(&import_decl.src);
+        self.visit_with_parent(SkipReason::ModuleSource, import_decl);
+    }
+
+    fn visit_mut_named_export(&mut self, export: &mut NamedExport) {
+        if let Some(src) = &export.src {
+            self.report_module_source(src);
+        }
+        self.visit_with_parent(SkipReason::ModuleSource, export);
+    }
+
+    fn visit_mut_export_all(&mut self, export: &mut ExportAll) {
+        self.report_module_source(&export.src);
+        self.visit_with_parent(SkipReason::ModuleSource, export);
+    }
+
+    /// 객체 프로퍼티 키: { ["키"]: value }
+    /// 문자열 키({ "키": value })는 Expr이 아니므로 애초에 변환되지 않음
+    fn visit_mut_prop_name(&mut self, prop_name: &mut PropName) {
+        self.visit_with_parent(SkipReason::PropertyKey, prop_name);
+    }
+
+    /// 계산된 멤버 키: labels["대기중"]
+    fn visit_mut_member_prop(&mut self, prop: &mut MemberProp) {
+        self.visit_with_parent(SkipReason::PropertyKey, prop);
+    }
+
+    /// TS 타입 위치: type Status = "대기" | "완료"
+    fn visit_mut_ts_type(&mut self, ts_type: &mut TsType) {
+        self.visit_with_parent(SkipReason::TsType, ts_type);
+    }
+
+    /// 타입 위치의 문자열 리터럴은 Expr이 아니므로 여기서 기록
+    fn visit_mut_ts_lit(&mut self, ts_lit: &mut TsLit) {
+        if let (TsLit::Str(str_lit), Some(reason)) = (&*ts_lit, parent_skip_reason(&self.parents)) {
+            let value = str_lit.value.to_string_lossy().to_string();
+            if self.is_source_text(&value) {
+                self.report_skip(&value, reason, str_lit.span);
+            }
+        }
+    }
+
+    /// enum 멤버 값: enum Status { Pending = "대기" }
+    fn visit_mut_ts_enum_member(&mut self, member: &mut TsEnumMember) {
+        self.visit_with_parent(SkipReason::EnumMember, member);
+    }
+
+    /// declare 구문: declare const VERSION = "버전";
+    fn visit_mut_var_decl(&mut self, var_decl: &mut VarDecl) {
+        if var_decl.declare {
+            self.visit_with_parent(SkipReason::AmbientDeclaration, var_decl);
+        } else {
+            var_decl.visit_mut_children_with(self);
+        }
+    }
+
+    fn visit_mut_ts_module_decl(&mut self, module_decl: &mut TsModuleDecl) {
+        if module_decl.declare {
+            self.visit_with_parent(SkipReason::AmbientDeclaration, module_decl);
+        } else {
+            module_decl.visit_mut_children_with(self);
+        }
+    }
+
+    /// export default function Page() {} 컴포넌트
+    /// 이름 없는 export default function () {}은 "default"라는 이름으로 기록
+    fn visit_mut_export_default_decl(&mut self, export: &mut ExportDefaultDecl) {
+        match &export.decl {
+            DefaultDecl::Fn(FnExpr {
+                ident: Some(ident), ..
+            }) if crate::ast_helpers::is_react_component(&ident.sym) => {
+                let name = ident.sym.to_string();
+                self.visit_component(name, export);
+            }
+            DefaultDecl::Fn(FnExpr { ident: None, .. }) => {
+                self.visit_component(StringConstants::DEFAULT_EXPORT_COMPONENT.to_string(), export);
+            }
+            _ => export.visit_mut_children_with(self),
+        }
+    }
+
+    /// export default () => ... 컴포넌트 ("default"라는 이름으로 기록)
+    fn visit_mut_export_default_expr(&mut self, export: &mut ExportDefaultExpr) {
+        if crate::ast_helpers::is_component_initializer(&export.expr) {
+            self.visit_component(StringConstants::DEFAULT_EXPORT_COMPONENT.to_string(), export);
+        } else {
+            export.visit_mut_children_with(self);
+        }
+    }
+
+    /// 화살표 함수/함수 표현식 컴포넌트: const LoginForm = () => ...
+    /// semantic 키를 위해 컴포넌트 이름을 문맥에 쌓음
+    fn visit_mut_var_declarator(&mut self, declarator: &mut VarDeclarator) {
+        // 지연 번역 단계에서 이미 방문한 상수
+        if self.deferred_declarators.contains(&declarator.span) {
+            return;
+        }
+
+        let component = match (&declarator.name, declarator.init.as_deref()) {
+            (Pat::Ident(binding), Some(init))
+                if crate::ast_helpers::is_react_component(&binding.id.sym)
+                    && crate::ast_helpers::is_component_initializer(init) =>
+            {
+                Some(binding.id.sym.to_string())
+            }
+            _ => None,
+        };
+
+        match component {
+            Some(name) => {
+                let previous_binding = self.binding.take();
+                self.visit_component(name, declarator);
+                self.binding = previous_binding;
+            }
+            // semantic 키: const title = "설정" → SettingsPage.title
+            None => {
+                let binding = match &declarator.name {
+                    Pat::Ident(binding) => Some(binding.id.sym.to_string()),
+                    _ => self.binding.clone(),
+                };
+                self.visit_with_binding(binding, declarator);
+            }
+        }
+    }
+
+    /// 객체 프로퍼티 값: const labels = { save: "저장" } → labels.save
+    fn visit_mut_key_value_prop(&mut self, prop: &mut KeyValueProp) {
+        let binding = self.prop_binding(&prop.key);
+        self.visit_with_binding(binding, prop);
+    }
+
+    /// JSX 엘리먼트 태그를 문맥에 쌓음 (semantic 키)
+    /// 속성 값 안의 엘리먼트는 바깥 속성 문맥을 이어받지 않음
+    fn visit_mut_jsx_element(&mut self, element: &mut JSXElement) {
+        let outer_attribute = self.jsx_attribute.take();
+        self.jsx_elements.push((
+            jsx_element_name(&element.opening.name),
+            jsx_element_qualifier(&element.opening),
+        ));
+        element.visit_mut_children_with(self);
+        self.jsx_elements.pop();
+        self.jsx_attribute = outer_attribute;
+    }
+
+    /// i18n-ignore-file이 있으면 파일 전체를 변환하지 않음
+    fn visit_mut_module(&mut self, module: &mut Module) {
+        if self.ignore_directives.is_file_ignored() {
+            return;
+        }
+        module.visit_mut_children_with(self);
+    }
+
+    /// 구문 단위로 span을 쌓아 부모 구문의 i18n-ignore 주석을 확인할 수 있게 함
+    fn visit_mut_module_item(&mut self, item: &mut ModuleItem) {
+        let span = item.span();
+        self.visit_statement(span, item);
+    }
+
+    fn visit_mut_stmt(&mut self, stmt: &mut Stmt) {
+        let span = stmt.span();
+        self.visit_statement(span, stmt);
+    }
+
+    /// i18n-ignore 문맥 안의 JSX 텍스트는 변환하지 않고 기록만 함
+    fn visit_mut_jsx_text(&mut self, text: &mut JSXText) {
+        if let Some(reason) = parent_skip_reason(&self.parents) {
+            let value = clean_jsx_text(&text.value);
+            if self.is_source_text(&value) {
+                self.report_skip(&value, reason, text.span);
+            }
+        }
+    }
+
+    /// 모듈 최상단 디렉티브 프롤로그('use client' 등)는 스킵
+    /// 지연 번역 상수는 컴포넌트보다 먼저 처리 (사용처 감싸기에 값 모양이 필요)
+    fn visit_mut_module_items(&mut self, items: &mut Vec<ModuleItem>) {
+        self.defer_module_constants(items);
+
+        let directive_count = items
+            .iter()
+            .take_while(|item| matches!(item, ModuleItem::Stmt(stmt) if is_directive(stmt)))
+            .count();
+
+        for (index, item) in items.iter_mut().enumerate() {
+            if index < directive_count {
+                self.visit_with_parent(SkipReason::Directive, item);
+            } else {
+                item.visit_mut_with(self);
+            }
+        }
+    }
+
+    /// 함수 body 최상단 디렉티브 프롤로그('use server' 등)는 스킵
+    fn visit_mut_stmts(&mut self, stmts: &mut Vec<Stmt>) {
+        let directive_count = stmts.iter().take_while(|stmt| is_directive(stmt)).count();
+
+        for (index, stmt) in stmts.iter_mut().enumerate() {
+            if index < directive_count {
+                self.visit_with_parent(SkipReason::Directive, stmt);
+            } else {
+                stmt.visit_mut_with(self);
+            }
+        }
+    }
+
+    /// StringLiteral 변환 (하위 호환성을 위해 유지)
+    fn visit_mut_str(&mut self, n: &mut Str) {
+        // visit_mut_expr에서 처리하므로 여기서는 아무것도 하지 않음
+        let _ = n;
+    }
+
+    /// JSXAttribute 변환
+    /// TypeScript 버전과 동일한 로직 (isJSXAttribute 분기):
+    /// placeholder="검색" → placeholder={t("검색")}
+    ///
+    /// 속성 정책:
+    /// - className, id, key, href, style, data-* 는 값 전체를 건드리지 않음
+    /// - 그 외 속성(alt, title, placeholder, aria-* 포함)의 한국어 문자열은 번역
+    fn visit_mut_jsx_attr(&mut self, attr: &mut JSXAttr) {
+        let name = jsx_attr_name(&attr.name);
+        if is_non_translatable_attribute(&name) {
+            return;
+        }
+
+        // semantic 키: LoginForm.input.placeholder
+        let outer_attribute = self.jsx_attribute.replace(name.clone());
+        self.transform_jsx_attr(&name, attr);
+        self.jsx_attribute = outer_attribute;
+    }
+
+    /// JSX children 변환 (JSXText)
+    /// TypeScript 버전과 동일한 로직:
+    /// 1. 빈 텍스트나 공백만 있는 경우 스킵
+    /// 2. 한국어가 포함된 텍스트만 처리
+    /// 3. {t("...")} JSXExpressionContainer로 교체
+    ///
+    /// JSXText는 스스로를 ExpressionContainer로 바꿀 수 없으므로 부모의 children 레벨에서 교체
+    /// 인접한 텍스트와 {expr}은 번역가가 어순을 바꿀 수 있도록 하나의 메시지로 합침
+    /// 공백은 React JSX 규칙을 따름: 줄바꿈 + 들여쓰기는 합쳐지고,
+    /// React가 렌더링하는 앞뒤 공백은 {" "}로 t() 바깥에 유지
+    fn visit_mut_jsx_element_childs(&mut self, children: &mut Vec<JSXElementChild>) {
+        // 이미 t() 인자 안에 있는 JSX 등은 그대로 유지
+        if parent_skip_reason(&self.parents).is_some() {
+            children.visit_mut_children_with(self);
+            return;
+        }
+
+        let ignored = self.ignored_jsx_children(children);
+
+        // 리치 텍스트 모드: 텍스트 + 인라인 엘리먼트를 하나의 메시지로 묶음
+        // i18n-ignore가 적용된 자식이 있으면 메시지로 묶지 않음
+        if self.config.rich_text.is_some() && !ignored.contains(&true) {
+            if let Some(rich_text) = self.collect_rich_text(children) {
+                self.mark_modified();
+
+                let (leading, core, trailing) = split_jsx_whitespace(&rich_text.message);
+                let (leading, trailing) = (leading.to_string(), trailing.to_string());
+                let rich_text = RichTextMessage {
+                    message: core.to_string(),
+                    ..rich_text
+                };
+
+                children.clear();
+                if !leading.is_empty() {
+                    children.push(create_jsx_string_child(&leading));
+                }
+                children.push(self.create_rich_text_child(rich_text));
+                if !trailing.is_empty() {
+                    children.push(create_jsx_string_child(&trailing));
+                }
+                return;
+            }
+        }
+
+        // 중첩된 엘리먼트/표현식 먼저 변환
+        for (child, &is_ignored) in children.iter_mut().zip(&ignored) {
+            if is_ignored {
+                self.visit_with_parent(SkipReason::IgnoreComment, child);
+            } else if !self.is_deferred_usage_child(child) {
+                child.visit_mut_with(self);
+            }
+        }
+
+        let mut new_children = Vec::with_capacity(children.len());
+        let mut run = Vec::new();
+
+        for (child, is_ignored) in children.drain(..).zip(ignored) {
+            if is_ignored {
+                // i18n-ignore가 적용된 자식은 메시지를 끊고 그대로 유지
+                self.flush_jsx_message_run(&mut run, &mut new_children);
+                new_children.push(child);
+                continue;
+            }
+            if is_message_child(&child) {
+                run.push(child);
+                continue;
+            }
+            self.flush_jsx_message_run(&mut run, &mut new_children);
+            new_children.push(child);
+        }
+        self.flush_jsx_message_run(&mut run, &mut new_children);
+
+        *children = new_children;
+    }
+}
+
+/// `+` 연결 체인을 피연산자 목록으로 펼침
+/// JS는 왼쪽부터 평가하므로 문자열이 나오기 전의 피연산자(a + b + "건"의 a + b)는
+/// 숫자 덧셈일 수 있어 하나의 표현식으로 유지
+fn flatten_concat(bin: &BinExpr) -> Vec<&Expr> {
+    let is_string_operand = |expr: &Expr| matches!(expr, Expr::Lit(Lit::Str(_)) | Expr::Tpl(_));
+
+    let mut operands = match bin.left.as_ref() {
+        Expr::Bin(left) if left.op == BinaryOp::Add => {
+            let left_operands = flatten_concat(left);
+            if left_operands.iter().any(|operand| is_string_operand(operand)) {
+                left_operands
+            } else {
+                vec![bin.left.as_ref()]
+            }
+        }
+        left => vec![left],
+    };
+    operands.push(bin.right.as_ref());
+    operands
+}
+
+/// 콜백 인자 패턴이 바인딩하는 이름과 그 값 모양
+/// (option) → option, ({ label, meta: { title } }) → label, title
+fn pattern_aliases(pat: &Pat, shape: &DeferredShape) -> Vec<(String, DeferredShape)> {
+    match pat {
+        Pat::Ident(binding) => vec![(binding.id.sym.to_string(), shape.clone())],
+        Pat::Object(object) => object
+            .props
+            .iter()
+            .flat_map(|prop| match prop {
+                ObjectPatProp::Assign(assign) => shape
+                    .prop(&assign.key.id.sym)
+                    .map(|shape| vec![(assign.key.id.sym.to_string(), shape)])
+                    .unwrap_or_default(),
+                ObjectPatProp::KeyValue(key_value) => prop_key_name(&key_value.key)
+                    .and_then(|key| shape.prop(&key))
+                    .map(|shape| pattern_aliases(&key_value.value, &shape))
+                    .unwrap_or_default(),
+                ObjectPatProp::Rest(_) => Vec::new(),
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// 번역 대상 판단 결과
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TextMatch {
+    Translate,
+    /// 자연어 점수가 임계값 근처: 변환하지 않고 보고
+    Borderline,
+    Ignore,
+}
+
+/// 메시지 조각 (텍스트 또는 interpolation 표현식)
+enum MessagePart {
+    Text(String),
+    Expr(Box<Expr>),
+    /// i18next 포맷을 붙인 표현식: {{price, currency(KRW)}}
+    Formatted(Box<Expr>, String),
+}
+
+impl MessagePart {
+    /// interpolation으로 넘길 표현식 (텍스트면 None)
+    fn value(&self) -> Option<&Expr> {
+        match self {
+            MessagePart::Text(_) => None,
+            MessagePart::Expr(expr) | MessagePart::Formatted(expr, _) => Some(expr),
+        }
+    }
+}
+
+/// 포맷 호출 분석 결과
+enum FormatCall {
+    /// 원래 값과 i18next 포맷: price.toLocaleString() → (price, "number")
+    Supported(Box<Expr>, String),
+    /// 포맷 호출이지만 옵션 등을 i18next 포맷으로 옮길 수 없음
+    Unsupported,
+}
+
+/// toLocaleString / toLocaleDateString / Intl.*Format().format() 호출 분석
+/// 포맷 호출이 아니면 None
+fn format_call(expr: &Expr) -> Option<FormatCall> {
+    let Expr::Call(CallExpr {
+        callee: Callee::Expr(callee),
+        args,
+        ..
+    }) = expr
+    else {
+        return None;
+    };
+    let Expr::Member(MemberExpr {
+        obj,
+        prop: MemberProp::Ident(method),
+        ..
+    }) = callee.as_ref()
+    else {
+        return None;
+    };
+
+    let supported = |format: &str| Some(FormatCall::Supported(obj.clone(), format.to_string()));
+    match &*method.sym {
+        Formatting::TO_LOCALE_STRING if is_locale_only(args) => supported(Formatting::NUMBER),
+        Formatting::TO_LOCALE_DATE_STRING if is_locale_only(args) => supported(Formatting::DATETIME),
+        Formatting::FORMAT_METHOD => {
+            let (constructor, constructor_args) = intl_constructor(obj)?;
+            let value = match args.as_slice() {
+                [ExprOrSpread { spread: None, expr }] => expr.clone(),
+                _ => return Some(FormatCall::Unsupported),
+            };
+            let format = match constructor {
+                Formatting::NUMBER_FORMAT => intl_number_format(constructor_args),
+                _ if is_locale_only(constructor_args) => Some(Formatting::DATETIME.to_string()),
+                _ => None,
+            };
+            Some(match format {
+                Some(format) => FormatCall::Supported(value, format),
+                None => FormatCall::Unsupported,
+            })
+        }
+        name if name.starts_with(Formatting::LOCALE_METHOD_PREFIX) => Some(FormatCall::Unsupported),
+        _ => None,
+    }
+}
+
+/// 인자가 없거나 로케일 문자열 하나뿐인지 확인 (로케일은 i18next 현재 언어로 대체)
+fn is_locale_only(args: &[ExprOrSpread]) -> bool {
+    match args {
+        [] => true,
+        [ExprOrSpread { spread: None, expr }] => matches!(expr.as_ref(), Expr::Lit(Lit::Str(_))),
+        _ => false,
+    }
+}
+
+/// new Intl.NumberFormat(...) / Intl.DateTimeFormat(...)의 생성자 이름과 인자
+fn intl_constructor(expr: &Expr) -> Option<(&'static str, &[ExprOrSpread])> {
+    let (callee, args) = match expr {
+        Expr::New(NewExpr { callee, args, .. }) => {
+            (callee.as_ref(), args.as_deref().unwrap_or_default())
+        }
+        Expr::Call(CallExpr {
+            callee: Callee::Expr(callee),
+            args,
+            ..
+        }) => (callee.as_ref(), args.as_slice()),
+        Expr::Paren(paren) => return intl_constructor(&paren.expr),
+        _ => return None,
+    };
+    let Expr::Member(MemberExpr {
+        obj,
+        prop: MemberProp::Ident(name),
+        ..
+    }) = callee
+    else {
+        return None;
+    };
+    if !matches!(obj.as_ref(), Expr::Ident(intl) if &*intl.sym == Formatting::INTL) {
+        return None;
+    }
+
+    [Formatting::NUMBER_FORMAT, Formatting::DATE_TIME_FORMAT]
+        .into_iter()
+        .find(|constructor| *constructor == &*name.sym)
+        .map(|constructor| (constructor, args))
+}
+
+/// Intl.NumberFormat 인자를 i18next 포맷으로 변환
+/// 로케일만 있으면 number, { style: "currency", currency: "USD" }면 currency(USD)
+/// 그 밖의 옵션은 옮길 수 없으므로 None
+fn intl_number_format(args: &[ExprOrSpread]) -> Option<String> {
+    let options = match args {
+        [] | [_] if is_locale_only(args) => return Some(Formatting::NUMBER.to_string()),
+        [locale, ExprOrSpread { spread: None, expr }] if is_locale_only(std::slice::from_ref(locale)) => {
+            match expr.as_ref() {
+                Expr::Object(options) => options,
+                _ => return None,
+            }
+        }
+        _ => return None,
+    };
+
+    let mut style = None;
+    let mut currency = None;
+    for prop in &options.props {
+        let PropOrSpread::Prop(prop) = prop else {
+            return None;
+        };
+        let Prop::KeyValue(KeyValueProp {
+            key: PropName::Ident(key),
+            value,
+        }) = prop.as_ref()
+        else {
+            return None;
+        };
+        let Expr::Lit(Lit::Str(value)) = value.as_ref() else {
+            return None;
+        };
+        let value = value.value.to_string_lossy().to_string();
+        match &*key.sym {
+            Formatting::STYLE_OPTION => style = Some(value),
+            Formatting::CURRENCY_OPTION => currency = Some(value),
+            _ => return None,
+        }
+    }
+
+    match (style.as_deref(), currency) {
+        (Some(Formatting::CURRENCY), Some(code)) => {
+            Some(format!("{}({})", Formatting::CURRENCY, code))
+        }
+        _ => None,
+    }
+}
+
+/// Date getter 호출이면 Date 객체 반환: d.getDate() → d, d.getMonth() + 1 → d
+fn date_getter_object(expr: &Expr) -> Option<&Expr> {
+    match expr {
+        Expr::Paren(paren) => date_getter_object(&paren.expr),
+        Expr::Bin(BinExpr {
+            op: BinaryOp::Add,
+            left,
+            right,
+            ..
+        }) if matches!(right.as_ref(), Expr::Lit(Lit::Num(num)) if num.value == 1.0) => {
+            date_getter_object(left)
+        }
+        Expr::Call(CallExpr {
+            callee: Callee::Expr(callee),
+            args,
+            ..
+        }) if args.is_empty() => match callee.as_ref() {
+            Expr::Member(MemberExpr {
+                obj,
+                prop: MemberProp::Ident(getter),
+                ..
+            }) if Formatting::DATE_GETTERS.contains(&&*getter.sym) => Some(obj),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// start의 Date getter부터 같은 Date의 getter가 날짜 구분 텍스트로만 이어지는 마지막 위치
+/// 예: [d.getMonth() + 1, "월 ", d.getDate(), "일"] → 2
+fn date_getter_run_end(parts: &[MessagePart], start: usize, date: &Expr) -> usize {
+    let is_separator = |text: &str| {
+        text.chars().all(|c| {
+            c.is_whitespace()
+                || Formatting::DATE_UNITS.contains(&c)
+                || Formatting::DATE_SEPARATORS.contains(&c)
+        })
+    };
+
+    let mut last = start;
+    while let (Some(MessagePart::Text(separator)), Some(MessagePart::Expr(next))) =
+        (parts.get(last + 1), parts.get(last + 2))
+    {
+        let same_date = date_getter_object(next).is_some_and(|next| next.eq_ignore_span(date));
+        if !same_date || !is_separator(separator) {
+            break;
+        }
+        last += 2;
+    }
+    last
+}
+
+/// 텍스트가 조사로 시작하면 (조사 표기, 조사 쌍) 반환: "을 삭제" → ("을", "을/를")
+/// 조사 뒤에 한글이 이어지면 조사가 아닌 단어의 일부로 봄: "이상", "로그인", "은행"
+fn leading_josa(text: &str) -> Option<(&'static str, &'static str)> {
+    Josa::PARTICLES.into_iter().find(|(particle, _)| {
+        text.strip_prefix(particle).is_some_and(|rest| {
+            !rest
+                .chars()
+                .next()
+                .is_some_and(|c| RegexPatterns::korean_text().is_match(c.encode_utf8(&mut [0; 4])))
+        })
+    })
+}
+
+/// 텍스트 앞의 통화 단위를 제거하고 통화 코드 반환: "원 결제" → " 결제", KRW
+/// 단위 뒤는 텍스트 끝, 공백/문장 부호, 조사여야 함: "원본", "원래", "원하는"은 단어의 일부
+fn strip_currency_suffix(text: &mut String) -> Option<&'static str> {
+    let (suffix, code) = Formatting::CURRENCY_SUFFIXES.into_iter().find(|(suffix, _)| {
+        text.strip_prefix(suffix).is_some_and(|rest| {
+            rest.chars().next().is_none_or(|c| !c.is_alphanumeric()) || leading_josa(rest).is_some()
+        })
+    })?;
+    text.drain(..suffix.len());
+    Some(code)
+}
+
+/// 텍스트 조각 추가 (직전 조각도 텍스트면 이어붙임)
+fn push_message_text(parts: &mut Vec<MessagePart>, text: &str) {
+    if let Some(MessagePart::Text(last)) = parts.last_mut() {
+        last.push_str(text);
+    } else {
+        parts.push(MessagePart::Text(text.to_string()));
+    }
+}
+
+/// 하나의 메시지로 합칠 수 있는 JSX child인지 확인
+/// 텍스트, 그리고 값을 그대로 출력하는 단순 표현식({name}, {user.name}, {3}, {" "})만 허용
+/// 조건부 렌더링, map, JSX를 반환할 수 있는 호출 등은 메시지를 끊음
+fn is_message_child(child: &JSXElementChild) -> bool {
+    match child {
+        JSXElementChild::JSXText(_) => true,
+        JSXElementChild::JSXExprContainer(JSXExprContainer {
+            expr: JSXExpr::Expr(expr),
+            ..
+        }) => matches!(
+            expr.as_ref(),
+            Expr::Ident(_) | Expr::Member(_) | Expr::OptChain(_) | Expr::Lit(Lit::Str(_)) | Expr::Lit(Lit::Num(_))
+        ),
+        _ => false,
+    }
+}
+
+/// i18n-ignore 판단에 쓰는 JSX 자식의 시작 위치
+/// JSXText는 앞쪽 공백/줄바꿈을 건너뛴 실제 텍스트 위치를 사용 (next-line 지시어 대응)
+fn jsx_child_start(child: &JSXElementChild) -> Span {
+    match child {
+        JSXElementChild::JSXText(text) => {
+            let leading = text.value.len() - text.value.trim_start().len();
+            let lo = BytePos(text.span.lo.0 + leading as u32);
+            Span::new(lo, text.span.hi.max(lo))
+        }
+        _ => child.span(),
+    }
+}
+
+/// 리치 텍스트 수집 결과
+#[derive(Default)]
+struct RichTextMessage {
+    /// 인덱스 태그가 포함된 메시지: "약관에 <0>동의</0>합니다"
+    message: String,
+    /// 태그 인덱스 순서대로의 엘리먼트 (children 제거됨)
+    components: Vec<JSXElement>,
+    /// interpolation 값
+    values: Vec<PropOrSpread>,
+    /// interpolation 변수명 배정 상태
+    names: InterpolationNames,
+}
+
+/// 리치 텍스트에 포함할 수 있는 인라인 엘리먼트인지 확인
+/// 인라인 HTML 태그 또는 컴포넌트이고, 자식이 텍스트/표현식뿐이어야 함
+fn is_inline_element(element: &JSXElement) -> bool {
+    let is_inline_name = match &element.opening.name {
+        JSXElementName::Ident(ident) => {
+            RegexPatterns::react_component().is_match(&ident.sym)
+                || InlineElements::TAGS.contains(&&*ident.sym)
+        }
+        JSXElementName::JSXMemberExpr(_) => true,
+        JSXElementName::JSXNamespacedName(_) => false,
+    };
+
+    is_inline_name
+        && element.children.iter().all(|child| {
+            matches!(
+                child,
+                JSXElementChild::JSXText(_) | JSXElementChild::JSXExprContainer(_)
+            )
+        })
+}
+
+/// 엘리먼트에서 children을 제거하여 self-closing으로 만듦
+/// <a href="/terms">동의</a> → <a href="/terms" />
+fn strip_jsx_children(element: &JSXElement) -> JSXElement {
+    JSXElement {
+        span: element.span,
+        opening: JSXOpeningElement {
+            self_closing: true,
+            ..element.opening.clone()
+        },
+        children: vec![],
+        closing: None,
+    }
+}
+
+/// semantic 키에서 엘리먼트를 구분하는 id/name/type 속성 값
+/// <button type="submit"> → "submit", <input name="user-email"> → "user_email"
+fn jsx_element_qualifier(opening: &JSXOpeningElement) -> Option<String> {
+    KeyStrategies::QUALIFIER_ATTRIBUTES.iter().find_map(|qualifier| {
+        opening.attrs.iter().find_map(|attr| match attr {
+            JSXAttrOrSpread::JSXAttr(JSXAttr {
+                name,
+                value: Some(JSXAttrValue::Str(str_lit)),
+                ..
+            }) if jsx_attr_name(name) == *qualifier => {
+                Some(slugify(&str_lit.value.to_string_lossy())).filter(|slug| !slug.is_empty())
+            }
+            _ => None,
+        })
+    })
+}
+
+/// name={value} 형태의 JSX 속성 생성
+fn create_jsx_attr(name: &str, value: JSXAttrValue) -> JSXAttrOrSpread {
+    JSXAttrOrSpread::JSXAttr(JSXAttr {
+        span: DUMMY_SP,
+        name: JSXAttrName::Ident(IdentName {
+            span: DUMMY_SP,
+            sym: name.into(),
+        }),
+        value: Some(value),
+    })
+}
+
+/// 디렉티브 프롤로그 구문인지 확인: 'use client'; 'use strict';
+fn is_directive(stmt: &Stmt) -> bool {
+    matches!(stmt, Stmt::Expr(expr_stmt) if matches!(expr_stmt.expr.as_ref(), Expr::Lit(Lit::Str(_))))
+}
+
+/// 템플릿 quasi의 문자열 값 (cooked 우선, 없으면 raw)
+fn quasi_value(quasi: &TplElement) -> String {
+    match &quasi.cooked {
+        Some(cooked) => cooked.to_string_lossy().to_string(),
+        None => quasi.raw.to_string(),
+    }
+}
+
+/// interpolation 객체 프로퍼티 생성
+/// 변수명과 표현식이 같은 식별자면 shorthand로 생성: { name }
+fn create_interpolation_prop(var_name: &str, expr: Box<Expr>) -> PropOrSpread {
+    if let Expr::Ident(ident) = expr.as_ref() {
+        if &*ident.sym == var_name {
+            return PropOrSpread::Prop(Box::new(Prop::Shorthand(ident.clone())));
+        }
+    }
+
+    PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+        key: PropName::Ident(IdentName {
+            span: DUMMY_SP,
+            sym: var_name.into(),
+        }),
+        value: expr,
+    })))
+}
+
+/// 공백 등 고정 문자열을 담은 JSX child 생성: {" "}
+fn create_jsx_string_child(value: &str) -> JSXElementChild {
+    JSXElementChild::JSXExprContainer(JSXExprContainer {
+        span: DUMMY_SP,
+        expr: JSXExpr::Expr(Box::new(Expr::Lit(Lit::Str(Str {
+            span: DUMMY_SP,
+            value: value.into(),
+            raw: None,
+        })))),
+    })
+}
+
+/// Module을 변환하고 결과 반환
 pub fn transform_module(module: &mut Module, source_code: String) -> (TransformResult, Vec<String>) {
-    let mut transformer = TranslationTransformer::new(source_code);
+    transform_module_with_config(module, source_code, &TransformerConfig::default(), None)
+}
+
+/// 설정을 적용하여 Module을 변환하고 결과 반환
+/// comments는 parse_file_with_comments가 반환한 주석 (i18n-ignore 지시어 확인용)
+pub fn transform_module_with_config(
+    module: &mut Module,
+    source_code: String,
+    config: &TransformerConfig,
+    comments: Option<&SingleThreadedComments>,
+) -> (TransformResult, Vec<String>) {
+    let ignore_directives = comments
+        .map(|comments| IgnoreDirectives::from_comments(comments, &source_code))
+        .unwrap_or_default();
+    let mut config = config.clone();
+    config.translation_callees.get_or_insert_with(|| {
+        translation_callees(
+            module,
+            StringConstants::DEFAULT_TRANSLATION_IMPORT_SOURCE,
+            StringConstants::GET_SERVER_TRANSLATION,
+        )
+    });
+    let mut transformer = TranslationTransformer::with_config(source_code, config)
+        .with_ignore_directives(ignore_directives);
     module.visit_mut_with(&mut transformer);
-    (
-        TransformResult::new(transformer.was_modified),
-        transformer.modified_functions,
-    )
+
+    let mut result = TransformResult::new(transformer.was_modified);
+    result.uses_trans_component = transformer.uses_trans_component;
+    result.skipped = transformer.skipped;
+    result.warnings = transformer.warnings;
+    result.translation_bindings = transformer.translation_bindings;
+    result.deferred_constants = transformer.deferred_constants.into_keys().collect();
+    result.deferred_constants.sort();
+    (result, transformer.modified_functions)
 }
@@ -0,0 +1,307 @@
+//! 파일 단위 클라이언트/서버 컴포넌트 분류
+//! ScriptConfig의 mode가 없을 때 AST를 보고 파일마다 useTranslation / getTranslations 중 하나를 고름
+
+use crate::ast::ast_helpers::{directive_prologue, is_component_initializer, is_react_component};
+use crate::utils::constants::{RegexPatterns, StringConstants};
+use swc_ecma_ast::*;
+use swc_ecma_visit::{Visit, VisitWith};
+
+/// 번역 바인딩 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComponentMode {
+    /// const { t } = useTranslation()
+    Client,
+    /// const { t } = await getTranslations()
+    Server,
+}
+
+impl ComponentMode {
+    /// ApplierConfig.mode 값 ("client", "server")
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ComponentMode::Client => StringConstants::MODE_CLIENT,
+            ComponentMode::Server => StringConstants::MODE_SERVER,
+        }
+    }
+}
+
+/// 분류 근거 (우선순위 순)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClassificationReason {
+    /// 'use client' 디렉티브
+    UseClientDirective,
+    /// 'use server' 디렉티브
+    UseServerDirective,
+    /// 이미 useTranslation()으로 t를 바인딩함
+    ClientTranslationBinding,
+    /// 이미 await getTranslations()으로 t를 바인딩함
+    ServerTranslationBinding,
+    /// React 훅 호출 (useState 등, 훅 이름)
+    HookUsage(String),
+    /// 이벤트 핸들러 prop (onClick 등, prop 이름)
+    EventHandler(String),
+    /// async 컴포넌트 (컴포넌트 이름)
+    AsyncComponent(String),
+    /// Next.js App Router(app/) 아래 파일: 기본이 서버 컴포넌트
+    AppRouter,
+    /// 근거가 없어 클라이언트로 처리
+    Default,
+}
+
+/// 파일 분류 결과
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Classification {
+    pub mode: ComponentMode,
+    pub reason: ClassificationReason,
+}
+
+impl Classification {
+    fn new(mode: ComponentMode, reason: ClassificationReason) -> Self {
+        Self { mode, reason }
+    }
+}
+
+/// 모듈을 클라이언트/서버 중 하나로 분류
+///
+/// 1. 'use client' / 'use server' 디렉티브
+/// 2. 기존 번역 바인딩 (useTranslation / await server_translation_function)
+/// 3. 훅 호출, 이벤트 핸들러 prop → 클라이언트 (서버 컴포넌트에서 쓸 수 없음)
+/// 4. async 컴포넌트 → 서버
+/// 5. 파일 위치: app/ 아래면 서버 (pages/ 등 그 밖은 클라이언트)
+pub fn classify_module(
+    module: &Module,
+    file_path: &str,
+    server_translation_function: &str,
+) -> Classification {
+    for directive in directive_prologue(module) {
+        match directive.as_str() {
+            StringConstants::USE_CLIENT_DIRECTIVE => {
+                return Classification::new(
+                    ComponentMode::Client,
+                    ClassificationReason::UseClientDirective,
+                )
+            }
+            StringConstants::USE_SERVER_DIRECTIVE => {
+                return Classification::new(
+                    ComponentMode::Server,
+                    ClassificationReason::UseServerDirective,
+                )
+            }
+            _ => {}
+        }
+    }
+
+    let mut signals = ComponentSignals {
+        server_translation_function,
+        ..Default::default()
+    };
+    module.visit_with(&mut signals);
+
+    if signals.client_binding {
+        return Classification::new(
+            ComponentMode::Client,
+            ClassificationReason::ClientTranslationBinding,
+        );
+    }
+    if signals.server_binding {
+        return Classification::new(
+            ComponentMode::Server,
+            ClassificationReason::ServerTranslationBinding,
+        );
+    }
+    if let Some(hook) = signals.hook {
+        return Classification::new(ComponentMode::Client, ClassificationReason::HookUsage(hook));
+    }
+    if let Some(handler) = signals.event_handler {
+        return Classification::new(
+            ComponentMode::Client,
+            ClassificationReason::EventHandler(handler),
+        );
+    }
+    if let Some(component) = signals.async_component {
+        return Classification::new(
+            ComponentMode::Server,
+            ClassificationReason::AsyncComponent(component),
+        );
+    }
+    if is_app_router_path(file_path) {
+        return Classification::new(ComponentMode::Server, ClassificationReason::AppRouter);
+    }
+
+    Classification::new(ComponentMode::Client, ClassificationReason::Default)
+}
+
+/// 모듈에 React 컴포넌트나 커스텀 훅이 있는지 확인 (중첩 포함)
+/// Transformer와 같은 기준: 이름이 is_react_component를 만족하는 function 선언,
+/// 컴포넌트 함수를 초기값으로 가진 변수, export default function (이름 없는 것 포함),
+/// export default로 내보낸 화살표 함수
+pub fn contains_react_component(module: &Module) -> bool {
+    let mut finder = ComponentFinder::default();
+    module.visit_with(&mut finder);
+    finder.found
+}
+
+/// Next.js App Router 디렉토리(app/, src/app/) 아래 파일인지 확인
+/// app/과 pages/가 모두 경로에 있으면 파일에 더 가까운 쪽을 따름
+fn is_app_router_path(file_path: &str) -> bool {
+    let normalized = file_path.replace('\\', "/");
+    normalized
+        .split('/')
+        .rev()
+        .skip(1)
+        .find(|segment| {
+            *segment == StringConstants::APP_ROUTER_DIR || *segment == StringConstants::PAGES_ROUTER_DIR
+        })
+        == Some(StringConstants::APP_ROUTER_DIR)
+}
+
+/// 분류에 쓰는 AST 신호 수집기 (처음 발견한 것만 기록)
+#[derive(Default)]
+struct ComponentSignals<'a> {
+    server_translation_function: &'a str,
+    client_binding: bool,
+    server_binding: bool,
+    hook: Option<String>,
+    event_handler: Option<String>,
+    async_component: Option<String>,
+}
+
+impl ComponentSignals<'_> {
+    fn record_component(&mut self, name: &str, function_is_async: bool) {
+        if function_is_async && is_react_component(name) && self.async_component.is_none() {
+            self.async_component = Some(name.to_string());
+        }
+    }
+
+    /// 이름 없는 export default 컴포넌트는 "default"로 기록
+    fn record_default_export(&mut self, function_is_async: bool) {
+        if function_is_async && self.async_component.is_none() {
+            self.async_component = Some(StringConstants::DEFAULT_EXPORT_COMPONENT.to_string());
+        }
+    }
+}
+
+impl Visit for ComponentSignals<'_> {
+    fn visit_call_expr(&mut self, call: &CallExpr) {
+        if let Callee::Expr(callee) = &call.callee {
+            if let Expr::Ident(ident) = callee.as_ref() {
+                let name = &*ident.sym;
+                if name == StringConstants::USE_TRANSLATION {
+                    self.client_binding = true;
+                } else if RegexPatterns::react_hook().is_match(name) && self.hook.is_none() {
+                    self.hook = Some(name.to_string());
+                }
+            }
+        }
+        call.visit_children_with(self);
+    }
+
+    fn visit_await_expr(&mut self, await_expr: &AwaitExpr) {
+        if let Expr::Call(CallExpr {
+            callee: Callee::Expr(callee),
+            ..
+        }) = await_expr.arg.as_ref()
+        {
+            if matches!(callee.as_ref(), Expr::Ident(ident) if &*ident.sym == self.server_translation_function)
+            {
+                self.server_binding = true;
+            }
+        }
+        await_expr.visit_children_with(self);
+    }
+
+    fn visit_jsx_attr(&mut self, attr: &JSXAttr) {
+        if let JSXAttrName::Ident(name) = &attr.name {
+            if RegexPatterns::event_handler_prop().is_match(&name.sym) && self.event_handler.is_none() {
+                self.event_handler = Some(name.sym.to_string());
+            }
+        }
+        attr.visit_children_with(self);
+    }
+
+    fn visit_fn_decl(&mut self, fn_decl: &FnDecl) {
+        self.record_component(&fn_decl.ident.sym, fn_decl.function.is_async);
+        fn_decl.visit_children_with(self);
+    }
+
+    fn visit_export_default_decl(&mut self, export: &ExportDefaultDecl) {
+        if let DefaultDecl::Fn(FnExpr { ident, function }) = &export.decl {
+            match ident {
+                Some(ident) => self.record_component(&ident.sym, function.is_async),
+                None => self.record_default_export(function.is_async),
+            }
+        }
+        export.visit_children_with(self);
+    }
+
+    fn visit_export_default_expr(&mut self, export: &ExportDefaultExpr) {
+        if let Expr::Arrow(ArrowExpr { is_async, .. }) = export.expr.as_ref() {
+            self.record_default_export(*is_async);
+        }
+        export.visit_children_with(self);
+    }
+
+    fn visit_var_declarator(&mut self, declarator: &VarDeclarator) {
+        if let Pat::Ident(binding) = &declarator.name {
+            match declarator.init.as_deref() {
+                Some(Expr::Arrow(arrow)) => self.record_component(&binding.id.sym, arrow.is_async),
+                Some(Expr::Fn(fn_expr)) => {
+                    self.record_component(&binding.id.sym, fn_expr.function.is_async)
+                }
+                _ => {}
+            }
+        }
+        declarator.visit_children_with(self);
+    }
+}
+
+/// 컴포넌트 존재 여부 수집기
+#[derive(Default)]
+struct ComponentFinder {
+    found: bool,
+}
+
+impl Visit for ComponentFinder {
+    fn visit_fn_decl(&mut self, fn_decl: &FnDecl) {
+        self.found |= is_react_component(&fn_decl.ident.sym);
+        if !self.found {
+            fn_decl.visit_children_with(self);
+        }
+    }
+
+    fn visit_fn_expr(&mut self, fn_expr: &FnExpr) {
+        // export default function Page() {}
+        self.found |= fn_expr
+            .ident
+            .as_ref()
+            .is_some_and(|ident| is_react_component(&ident.sym));
+        if !self.found {
+            fn_expr.visit_children_with(self);
+        }
+    }
+
+    /// export default function () {}
+    fn visit_export_default_decl(&mut self, export: &ExportDefaultDecl) {
+        self.found |= matches!(&export.decl, DefaultDecl::Fn(FnExpr { ident: None, .. }));
+        if !self.found {
+            export.visit_children_with(self);
+        }
+    }
+
+    /// export default () => ..., export default memo(() => ...)
+    fn visit_export_default_expr(&mut self, export: &ExportDefaultExpr) {
+        self.found |= is_component_initializer(&export.expr);
+        if !self.found {
+            export.visit_children_with(self);
+        }
+    }
+
+    fn visit_var_declarator(&mut self, declarator: &VarDeclarator) {
+        if let (Pat::Ident(binding), Some(init)) = (&declarator.name, declarator.init.as_deref()) {
+            self.found |= is_react_component(&binding.id.sym) && is_component_initializer(init);
+        }
+        if !self.found {
+            declarator.visit_children_with(self);
+        }
+    }
+}
@@ -0,0 +1,104 @@
+//! 모듈 최상위 상수(옵션 목록, 에러 맵, 메뉴 설정)의 지연 번역
+//! import 시점에 t()를 부르면 언어가 고정되므로 값은 마커(tKey)로 감싸 키만 남기고,
+//! 컴포넌트 안 사용처(STATUS_LABELS[s], item.label)를 t(...)로 감쌈
+
+use std::collections::BTreeMap;
+use swc_ecma_ast::*;
+
+/// 상수 값의 모양: 어떤 접근 경로가 번역 키(메시지)에 닿는지
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeferredShape {
+    /// 마커로 감싼 번역 키
+    Message,
+    /// 객체 리터럴: 프로퍼티 이름 → 값 모양
+    Object(BTreeMap<String, DeferredShape>),
+    /// 배열 리터럴: 모든 원소 모양을 합친 것
+    Array(Box<DeferredShape>),
+    /// 번역 키가 아니거나 알 수 없는 값
+    Other,
+}
+
+impl DeferredShape {
+    /// 메시지가 하나라도 있는지 (지연 번역 상수로 등록할지)
+    pub fn has_message(&self) -> bool {
+        match self {
+            DeferredShape::Message => true,
+            DeferredShape::Object(props) => props.values().any(DeferredShape::has_message),
+            DeferredShape::Array(element) => element.has_message(),
+            DeferredShape::Other => false,
+        }
+    }
+
+    /// 두 모양 합치기 (배열 원소, 계산된 키로 고른 객체 값)
+    /// 어느 쪽에서든 메시지가 아닐 수 있으면 Other
+    pub fn merge(self, other: DeferredShape) -> DeferredShape {
+        match (self, other) {
+            (a, b) if a == b => a,
+            (DeferredShape::Object(mut a), DeferredShape::Object(mut b)) => {
+                let keys: Vec<String> = a.keys().chain(b.keys()).cloned().collect();
+                let merged = keys
+                    .into_iter()
+                    .map(|key| {
+                        let shape = match (a.remove(&key), b.remove(&key)) {
+                            (Some(a), Some(b)) => a.merge(b),
+                            _ => DeferredShape::Other,
+                        };
+                        (key, shape)
+                    })
+                    .collect();
+                DeferredShape::Object(merged)
+            }
+            (DeferredShape::Array(a), DeferredShape::Array(b)) => {
+                DeferredShape::Array(Box::new(a.merge(*b)))
+            }
+            _ => DeferredShape::Other,
+        }
+    }
+
+    /// 멤버 접근 결과의 모양
+    /// - 객체: X.PENDING, X["PENDING"]은 그 값, X[s]는 모든 값을 합친 모양
+    /// - 배열: X[i]는 원소 모양 (length 등 프로퍼티는 None)
+    pub fn member(&self, prop: &MemberProp) -> Option<DeferredShape> {
+        match (self, prop) {
+            (DeferredShape::Object(props), MemberProp::Ident(name)) => props.get(&*name.sym).cloned(),
+            (DeferredShape::Object(props), MemberProp::Computed(computed)) => {
+                match computed.expr.as_ref() {
+                    Expr::Lit(Lit::Str(key)) => props.get(&*key.value.to_string_lossy()).cloned(),
+                    _ => props.values().cloned().reduce(DeferredShape::merge),
+                }
+            }
+            (DeferredShape::Array(element), MemberProp::Computed(_)) => Some((**element).clone()),
+            _ => None,
+        }
+    }
+
+    /// 객체 모양의 프로퍼티 (구조 분해: ({ label }) => ...)
+    pub fn prop(&self, name: &str) -> Option<DeferredShape> {
+        match self {
+            DeferredShape::Object(props) => props.get(name).cloned(),
+            _ => None,
+        }
+    }
+}
+
+/// 상수 초기값에서 감싸는 TS 표현식과 괄호 벗기기
+/// { ... } as const, [ ... ] satisfies Menu[], ({ ... })
+pub fn unwrap_constant_initializer(expr: &mut Expr) -> &mut Expr {
+    match expr {
+        Expr::TsConstAssertion(TsConstAssertion { expr, .. })
+        | Expr::TsAs(TsAsExpr { expr, .. })
+        | Expr::TsSatisfies(TsSatisfiesExpr { expr, .. })
+        | Expr::Paren(ParenExpr { expr, .. }) => unwrap_constant_initializer(expr),
+        _ => expr,
+    }
+}
+
+/// 객체 리터럴 프로퍼티 키 이름 (PENDING, "in-progress", 1)
+pub fn prop_key_name(key: &PropName) -> Option<String> {
+    match key {
+        PropName::Ident(ident) => Some(ident.sym.to_string()),
+        PropName::Str(str_lit) => Some(str_lit.value.to_string_lossy().to_string()),
+        PropName::Num(num) => Some(num.value.to_string()),
+        _ => None,
+    }
+}
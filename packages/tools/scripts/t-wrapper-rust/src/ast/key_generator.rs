@@ -0,0 +1,197 @@
+//! 번역 키 생성 전략
+//! ScriptConfig의 key_strategy에 따라 t()의 첫 번째 인자(키)를 생성
+
+use crate::utils::constants::KeyStrategies;
+use anyhow::{bail, Result};
+use std::collections::HashMap;
+
+/// 번역 키 생성 전략
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum KeyStrategy {
+    /// 원문 텍스트를 그대로 키로 사용 (기본값): t("안녕하세요")
+    #[default]
+    Source,
+    /// 원문의 안정적인 해시: t("3f1c9a0b2d4e", { defaultValue: "안녕하세요" })
+    Hash,
+    /// 로마자 변환 + 슬러그: t("annyeonghaseyo", { defaultValue: "안녕하세요" })
+    Slug,
+    /// 컴포넌트/엘리먼트/속성 문맥: t("LoginForm.input.placeholder", { defaultValue: "이메일" })
+    Semantic,
+}
+
+impl KeyStrategy {
+    /// ScriptConfig의 key_strategy 값 파싱 (None이면 Source)
+    pub fn parse(value: Option<&str>) -> Result<Self> {
+        match value {
+            None | Some(KeyStrategies::SOURCE) => Ok(Self::Source),
+            Some(KeyStrategies::HASH) => Ok(Self::Hash),
+            Some(KeyStrategies::SLUG) => Ok(Self::Slug),
+            Some(KeyStrategies::SEMANTIC) => Ok(Self::Semantic),
+            Some(other) => bail!(
+                "Unknown key strategy: {} (expected one of source, hash, slug, semantic)",
+                other
+            ),
+        }
+    }
+}
+
+/// semantic 전략에서 키를 만들 때 사용하는 문맥
+/// 예: LoginForm.button.submit, ProductCard.img.alt, SettingsPage.title
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct KeyContext {
+    /// 가장 가까운 React 컴포넌트 이름 (LoginForm)
+    /// keyPrefix를 쓰면 useTranslation에서 붙으므로 None
+    pub component: Option<String>,
+    /// 값이 할당된 변수 또는 객체 프로퍼티 이름 (const title = "설정" → title)
+    pub binding: Option<String>,
+    /// 가장 가까운 JSX 엘리먼트 태그 (button, img)
+    pub element: Option<String>,
+    /// 엘리먼트를 구분하는 id/name/type 속성 값 (<button type="submit"> → submit)
+    pub qualifier: Option<String>,
+    /// JSX 속성 이름 (alt, placeholder)
+    pub attribute: Option<String>,
+}
+
+impl KeyContext {
+    /// 비어 있지 않은 문맥 조각들 (컴포넌트 → 변수 → 엘리먼트 → 구분자 → 속성 순)
+    pub fn segments(&self) -> Vec<&str> {
+        [
+            &self.component,
+            &self.binding,
+            &self.element,
+            &self.qualifier,
+            &self.attribute,
+        ]
+        .into_iter()
+        .flatten()
+        .map(String::as_str)
+        .filter(|segment| !segment.is_empty())
+        .collect()
+    }
+}
+
+/// 전략에 따라 번역 키 생성
+///
+/// - 원문 기반 전략(source, slug)은 텍스트가 너무 길면 해시 키로 대체
+/// - slug로 만들 수 없는 텍스트(로마자화할 수 없는 문자만 있는 경우)도 해시 키로 대체
+/// - semantic은 문맥이 전혀 없으면 해시 키로 대체
+pub fn generate_key(strategy: KeyStrategy, text: &str, context: &KeyContext) -> String {
+    let is_long = text.chars().count() > KeyStrategies::MAX_TEXT_KEY_LENGTH;
+
+    match strategy {
+        KeyStrategy::Source if !is_long => text.to_string(),
+        KeyStrategy::Slug if !is_long => {
+            let slug = slugify(text);
+            if slug.is_empty() {
+                hash_key(text)
+            } else {
+                slug
+            }
+        }
+        KeyStrategy::Semantic => {
+            let segments = context.segments();
+            if segments.is_empty() {
+                hash_key(text)
+            } else {
+                segments.join(KeyStrategies::SEGMENT_SEPARATOR)
+            }
+        }
+        _ => hash_key(text),
+    }
+}
+
+/// 파일 안에서 생성한 키와 원문을 기억하여 충돌을 해결
+/// 같은 키에 다른 원문이 오면 `_2`, `_3` 접미사를 붙이고, 같은 원문이면 기존 키를 재사용
+#[derive(Debug, Clone, Default)]
+pub struct KeyRegistry {
+    messages: HashMap<String, String>,
+}
+
+impl KeyRegistry {
+    pub fn resolve(&mut self, key: String, message: &str) -> String {
+        let mut candidate = key.clone();
+        let mut suffix = 1;
+        loop {
+            match self.messages.get(&candidate) {
+                None => {
+                    self.messages.insert(candidate.clone(), message.to_string());
+                    return candidate;
+                }
+                Some(existing) if existing == message => return candidate,
+                Some(_) => {
+                    suffix += 1;
+                    candidate = format!("{}_{}", key, suffix);
+                }
+            }
+        }
+    }
+}
+
+/// 원문의 안정적인 해시 키 (FNV-1a 64비트, 16진수 앞 12자리)
+/// std의 DefaultHasher는 실행마다 값이 달라질 수 있어 직접 구현
+pub fn hash_key(text: &str) -> String {
+    const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    let hash = text.bytes().fold(FNV_OFFSET_BASIS, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME)
+    });
+    let hex = format!("{:016x}", hash);
+    hex[..KeyStrategies::HASH_LENGTH].to_string()
+}
+
+/// 텍스트를 키로 쓸 수 있는 슬러그로 변환
+/// 한글은 국어의 로마자 표기법(음절 단위)으로, 영문/숫자는 소문자로 유지하고 나머지는 `_`로 구분
+///
+/// 예: "저장 완료!" → "jeojang_wanryo", "Save changes" → "save_changes"
+pub fn slugify(text: &str) -> String {
+    let mut slug = String::new();
+    let mut pending_separator = false;
+
+    for c in text.chars() {
+        let romanized = if c.is_ascii_alphanumeric() {
+            Some(c.to_ascii_lowercase().to_string())
+        } else {
+            romanize_syllable(c)
+        };
+
+        match romanized {
+            Some(part) => {
+                if pending_separator && !slug.is_empty() {
+                    slug.push('_');
+                }
+                pending_separator = false;
+                slug.push_str(&part);
+            }
+            None => pending_separator = true,
+        }
+    }
+
+    slug
+}
+
+/// 한글 음절 하나를 로마자로 변환 (음운 변화는 반영하지 않음)
+fn romanize_syllable(c: char) -> Option<String> {
+    const INITIALS: [&str; 19] = [
+        "g", "kk", "n", "d", "tt", "r", "m", "b", "pp", "s", "ss", "", "j", "jj", "ch", "k", "t",
+        "p", "h",
+    ];
+    const MEDIALS: [&str; 21] = [
+        "a", "ae", "ya", "yae", "eo", "e", "yeo", "ye", "o", "wa", "wae", "oe", "yo", "u", "wo",
+        "we", "wi", "yu", "eu", "ui", "i",
+    ];
+    const FINALS: [&str; 28] = [
+        "", "k", "k", "k", "n", "n", "n", "t", "l", "k", "m", "l", "l", "l", "p", "l", "m", "p",
+        "p", "t", "t", "ng", "t", "t", "k", "t", "p", "t",
+    ];
+
+    let index = (c as u32).checked_sub(0xAC00)?;
+    if index >= 11172 {
+        return None;
+    }
+    let initial = (index / (21 * 28)) as usize;
+    let medial = ((index % (21 * 28)) / 28) as usize;
+    let final_ = (index % 28) as usize;
+
+    Some(format!("{}{}{}", INITIALS[initial], MEDIALS[medial], FINALS[final_]))
+}
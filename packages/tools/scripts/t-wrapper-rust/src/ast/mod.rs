@@ -1,8 +1,16 @@
 pub mod ast_helpers;
 pub mod ast_transformers;
+pub mod component_classifier;
 pub mod component_transformer;
+pub mod deferred_constants;
+pub mod key_generator;
+pub mod translation_scope;
 
 pub use ast_helpers::*;
 pub use ast_transformers::*;
+pub use component_classifier::*;
 pub use component_transformer::*;
+pub use deferred_constants::*;
+pub use key_generator::*;
+pub use translation_scope::*;
 
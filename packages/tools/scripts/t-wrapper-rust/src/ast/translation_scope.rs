@@ -0,0 +1,246 @@
+//! 컴포넌트 스코프의 번역 함수 이름 결정
+//! 컴포넌트 안에 t라는 다른 바인딩이나 참조(루프 변수, time 약어, lodash 헬퍼 등)가 있으면
+//! 훅 바인딩과 생성한 호출 모두 tr, t2 ... 중 비어 있는 이름을 씀
+//! 번역 훅/서버 번역 함수에서 구조 분해한 { t }만 기존 번역 바인딩으로 보고,
+//! props나 다른 함수 결과의 { t }는 이름 충돌로 봄
+
+use crate::utils::constants::StringConstants;
+use std::collections::HashSet;
+use swc_common::DUMMY_SP;
+use swc_ecma_ast::*;
+use swc_ecma_visit::{Visit, VisitWith};
+
+/// 기존 번역 바인딩으로 인정하는 초기값의 callee
+/// 파일의 import를 따라 정함 (import { useTranslation as useT } → useT, import * as i18n → i18n.useTranslation)
+#[derive(Debug, Clone, PartialEq)]
+pub struct TranslationCallees {
+    /// client: const { t } = useTranslation()
+    pub hook: Expr,
+    /// server: const { t } = await getTranslations()
+    pub server: Expr,
+}
+
+impl Default for TranslationCallees {
+    fn default() -> Self {
+        Self {
+            hook: Expr::Ident(Ident::new_no_ctxt(StringConstants::USE_TRANSLATION.into(), DUMMY_SP)),
+            server: Expr::Ident(Ident::new_no_ctxt(
+                StringConstants::GET_SERVER_TRANSLATION.into(),
+                DUMMY_SP,
+            )),
+        }
+    }
+}
+
+impl TranslationCallees {
+    /// 초기값이 번역 훅 호출이나 서버 번역 함수 await인지 확인
+    /// useClock(), parse(v), props 같은 다른 값은 번역 함수가 아님
+    fn is_translation_init(&self, init: &Expr) -> bool {
+        match init {
+            Expr::Call(call) => is_call_to(call, &self.hook),
+            Expr::Await(AwaitExpr { arg, .. }) => {
+                matches!(arg.as_ref(), Expr::Call(call) if is_call_to(call, &self.server))
+            }
+            Expr::Paren(ParenExpr { expr, .. }) => self.is_translation_init(expr),
+            _ => false,
+        }
+    }
+}
+
+/// 컴포넌트 안에서 쓰이는 식별자와 기존 번역 바인딩
+#[derive(Debug)]
+pub struct ComponentScope<'a> {
+    callees: &'a TranslationCallees,
+    /// 컴포넌트 안의 바인딩 이름과 값 참조 이름
+    /// 축약 프로퍼티 { t }는 t 참조로 포함, 프로퍼티 키({ t: 1 }), 타입 위치(interface, { t: number }), 라벨은 제외
+    names: HashSet<String>,
+    /// 컴포넌트 함수가 이미 번역 훅에서 구조 분해한 번역 함수: { t }, { t: tr }
+    translation_binding: Option<String>,
+    /// 중첩 함수 깊이 (0이면 컴포넌트 함수 자신)
+    depth: usize,
+}
+
+impl<'a> ComponentScope<'a> {
+    /// 컴포넌트 노드(FnDecl, VarDeclarator, ExportDefaultDecl 등)를 훑어 스코프 정보 수집
+    pub fn collect<N: VisitWith<Self>>(node: &N, callees: &'a TranslationCallees) -> Self {
+        let mut scope = Self {
+            callees,
+            names: HashSet::new(),
+            translation_binding: None,
+            depth: 0,
+        };
+        node.visit_with(&mut scope);
+        scope
+    }
+
+    /// 이 컴포넌트에서 쓸 번역 함수 이름
+    ///
+    /// 1. 이미 번역 훅에서 번역 함수를 구조 분해했으면 그 이름 (다시 실행해도 같은 이름)
+    /// 2. t가 쓰이지 않으면 t
+    /// 3. 아니면 tr, t2, t3, ... 중 컴포넌트 안에서 쓰이지 않는 첫 이름
+    ///    (props의 { t }, const { t } = useClock()도 이름 충돌)
+    pub fn translation_function_name(&self) -> String {
+        if let Some(existing) = &self.translation_binding {
+            return existing.clone();
+        }
+
+        std::iter::once(StringConstants::TRANSLATION_FUNCTION.to_string())
+            .chain(
+                StringConstants::TRANSLATION_FUNCTION_FALLBACKS
+                    .iter()
+                    .map(|name| name.to_string()),
+            )
+            .chain((2..).map(|n| format!("{}{}", StringConstants::TRANSLATION_FUNCTION, n)))
+            .find(|name| !self.names.contains(name))
+            .unwrap_or_default()
+    }
+
+    fn enter_function(&mut self, body: Option<&BlockStmt>) {
+        if self.depth == 0 && self.translation_binding.is_none() {
+            self.translation_binding =
+                body.and_then(|body| translation_binding_in_body(body, self.callees));
+        }
+    }
+}
+
+impl Visit for ComponentScope<'_> {
+    /// 변수/매개변수/구조 분해 바인딩: const t, (t) => ..., { t }, t = 1
+    fn visit_binding_ident(&mut self, binding: &BindingIdent) {
+        self.names.insert(binding.id.sym.to_string());
+    }
+
+    fn visit_fn_decl(&mut self, fn_decl: &FnDecl) {
+        self.names.insert(fn_decl.ident.sym.to_string());
+        fn_decl.visit_children_with(self);
+    }
+
+    fn visit_class_decl(&mut self, class_decl: &ClassDecl) {
+        self.names.insert(class_decl.ident.sym.to_string());
+        class_decl.visit_children_with(self);
+    }
+
+    /// 값 참조: t(x), render(t)
+    fn visit_expr(&mut self, expr: &Expr) {
+        if let Expr::Ident(ident) = expr {
+            self.names.insert(ident.sym.to_string());
+        }
+        expr.visit_children_with(self);
+    }
+
+    /// 축약 프로퍼티 { t }는 { t: t }라서 값 참조
+    /// { t: 1 }의 키는 IdentName이라 수집되지 않고 값과 계산된 키({ [t]: 1 })만 참조로 수집
+    fn visit_prop(&mut self, prop: &Prop) {
+        match prop {
+            Prop::Shorthand(ident) => {
+                self.names.insert(ident.sym.to_string());
+            }
+            _ => prop.visit_children_with(self),
+        }
+    }
+
+    /// JSX 멤버 태그의 객체는 값 참조: <t.Provider />
+    fn visit_jsx_object(&mut self, object: &JSXObject) {
+        if let JSXObject::Ident(ident) = object {
+            self.names.insert(ident.sym.to_string());
+        }
+        object.visit_children_with(self);
+    }
+
+    /// 타입 위치의 이름은 값 바인딩과 충돌하지 않음
+    fn visit_ts_type(&mut self, _: &TsType) {}
+
+    fn visit_ts_interface_decl(&mut self, _: &TsInterfaceDecl) {}
+
+    fn visit_ts_type_alias_decl(&mut self, _: &TsTypeAliasDecl) {}
+
+    fn visit_function(&mut self, function: &Function) {
+        self.enter_function(function.body.as_ref());
+        self.depth += 1;
+        function.visit_children_with(self);
+        self.depth -= 1;
+    }
+
+    fn visit_arrow_expr(&mut self, arrow: &ArrowExpr) {
+        let body = match arrow.body.as_ref() {
+            BlockStmtOrExpr::BlockStmt(body) => Some(body),
+            _ => None,
+        };
+        self.enter_function(body);
+        self.depth += 1;
+        arrow.visit_children_with(self);
+        self.depth -= 1;
+    }
+}
+
+/// 함수 body 최상위 변수 선언에서 번역 훅의 구조 분해 찾기
+/// const { t } = useTranslation(), const { t: tr } = await getTranslations()
+///
+/// 초기값이 callees 호출이 아니면(const { t } = useClock(), props) 번역 함수가 아니라 이름 충돌로 봄
+pub fn translation_binding_in_body(body: &BlockStmt, callees: &TranslationCallees) -> Option<String> {
+    body.stmts.iter().find_map(|stmt| match stmt {
+        Stmt::Decl(Decl::Var(var_decl)) => var_decl.decls.iter().find_map(|declarator| {
+            declarator
+                .init
+                .as_deref()
+                .filter(|init| callees.is_translation_init(init))
+                .and_then(|_| translation_binding_of_pat(&declarator.name))
+        }),
+        _ => None,
+    })
+}
+
+/// 패턴이 번역 함수를 구조 분해하면 그 지역 이름
+/// { t } → "t", { t: tr } → "tr", { t = fallback } → "t"
+///
+/// const t = ..., for (const t of ...) 같은 단순 바인딩은 번역 함수가 아니라 이름 충돌로 봄
+fn translation_binding_of_pat(pat: &Pat) -> Option<String> {
+    match pat {
+        Pat::Object(object) => object.props.iter().find_map(|prop| match prop {
+            ObjectPatProp::Assign(assign)
+                if &*assign.key.sym == StringConstants::TRANSLATION_FUNCTION =>
+            {
+                Some(assign.key.sym.to_string())
+            }
+            ObjectPatProp::KeyValue(key_value) if is_translation_key(&key_value.key) => {
+                match key_value.value.as_ref() {
+                    Pat::Ident(binding) => Some(binding.id.sym.to_string()),
+                    Pat::Assign(AssignPat { left, .. }) => match left.as_ref() {
+                        Pat::Ident(binding) => Some(binding.id.sym.to_string()),
+                        _ => None,
+                    },
+                    _ => None,
+                }
+            }
+            _ => None,
+        }),
+        _ => None,
+    }
+}
+
+/// call이 callee를 부르는지 (별칭/네임스페이스 멤버 포함, span 무시)
+fn is_call_to(call: &CallExpr, callee: &Expr) -> bool {
+    let Callee::Expr(expr) = &call.callee else {
+        return false;
+    };
+    match (expr.as_ref(), callee) {
+        (Expr::Ident(ident), Expr::Ident(expected)) => ident.sym == expected.sym,
+        (Expr::Member(member), Expr::Member(expected)) => {
+            matches!(
+                (member.obj.as_ref(), expected.obj.as_ref()),
+                (Expr::Ident(obj), Expr::Ident(expected_obj)) if obj.sym == expected_obj.sym
+            ) && matches!(
+                (&member.prop, &expected.prop),
+                (MemberProp::Ident(prop), MemberProp::Ident(expected_prop)) if prop.sym == expected_prop.sym
+            )
+        }
+        _ => false,
+    }
+}
+
+fn is_translation_key(key: &PropName) -> bool {
+    match key {
+        PropName::Ident(ident) => &*ident.sym == StringConstants::TRANSLATION_FUNCTION,
+        PropName::Str(str_lit) => str_lit.value.to_string_lossy() == StringConstants::TRANSLATION_FUNCTION,
+        _ => false,
+    }
+}
@@ -1,3 +1,5 @@
+use crate::utils::constants::StringConstants;
+
 #[derive(Debug, Clone)]
 pub struct ScriptConfig {
     pub source_pattern: String,
@@ -5,16 +7,72 @@ pub struct ScriptConfig {
     pub mode: Option<String>,
     pub framework: Option<String>,
     pub server_translation_function: Option<String>,
+    /// 리치 텍스트(텍스트 + 인라인 엘리먼트) 변환 방식
+    /// None: 비활성화, "trans": <Trans> 컴포넌트, "indexed": <0>태그</0> 형식의 t() 호출
+    pub rich_text: Option<String>,
+    /// 번역 대상 텍스트를 감지할 유니코드 스크립트 목록
+    /// 예: ["Hangul"], ["Hiragana", "Katakana", "Han"], ["Cyrillic"], ["Thai"]
+    /// None이면 한국어(한글 전체 블록)
+    pub source_scripts: Option<Vec<String>>,
+    /// 번역 대상 텍스트를 감지할 커스텀 정규식 (source_scripts보다 우선)
+    pub source_text_pattern: Option<String>,
+    /// 자연어(영어 등) 하드코딩 문자열 감지 임계값 (0.0 ~ 1.0)
+    /// None이면 비활성화, 권장값은 NaturalLanguage::DEFAULT_THRESHOLD
+    pub natural_language_threshold: Option<f64>,
+    /// 번역 키 생성 전략: None/"source"(원문), "hash", "slug", "semantic"
+    /// 원문이 아닌 키를 쓰면 원문은 defaultValue로 남김
+    pub key_strategy: Option<String>,
+    /// semantic 키의 컴포넌트 이름을 useTranslation(ns, { keyPrefix })로 넘길지 여부
+    /// true면 키는 "button.submit"처럼 컴포넌트 이름 없이 생성
+    pub key_prefix: bool,
+    /// 복수형으로 인식할 숫자 뒤 단위 명사 (None이면 Pluralization::COUNTER_SUFFIXES)
+    /// 예: `${n}명 참여` → t("{{count}}명 참여", { count: n })
+    pub counter_suffixes: Option<Vec<String>>,
+    /// 복수형으로 인식할 개수 변수 이름 (None이면 Pluralization::COUNTER_NAMES)
+    pub counter_names: Option<Vec<String>>,
+    /// interpolation 뒤 조사를 {{name, josa(을/를)}} 형식의 formatter 힌트로 바꿀지 여부
+    /// false면 조사는 그대로 두고 WarningKind::JosaParticle 경고로 보고 (josa formatter 등록 필요)
+    pub josa_hints: bool,
+    /// 컴포넌트/커스텀 훅이 없는 파일의 문자열 처리 방식
+    /// None/"skip": 변환하지 않고 보고, "global": i18n.t("..."), "lazy": tKey("...")
+    /// 컴포넌트가 있는 파일에서 컴포넌트 밖 문자열은 항상 보고
+    pub non_component_strategy: Option<String>,
+    /// "global" 전략에서 i18n 인스턴스를 default import할 모듈 (None이면 "@/lib/i18n")
+    pub i18n_instance_source: Option<String>,
+    /// "lazy" 전략의 마커 함수 이름 (None이면 "tKey", lazy_translation_source에서 import)
+    pub lazy_translation_function: Option<String>,
+    /// 마커 함수를 named import할 모듈 (예: "@/lib/i18n-markers")
+    /// i18nexus는 마커를 export하지 않으므로 기본값이 없고, "lazy" 전략이나 defer_constants를 쓰면 반드시 지정해야 함
+    pub lazy_translation_source: Option<String>,
+    /// 컴포넌트가 있는 파일의 모듈 최상위 상수 객체/배열(옵션 목록, 에러 맵, 메뉴 설정)을 지연 번역할지 여부
+    /// true면 값은 lazy_translation_function 마커로 감싸고, 컴포넌트 안 사용처를 t(...)로 감쌈
+    /// 예: { PENDING: "대기중" } → { PENDING: tKey("대기중") }, {STATUS_LABELS[s]} → {t(STATUS_LABELS[s])}
+    /// false면 상수 안 문자열은 SkipReason::NoComponentScope로 보고
+    pub defer_constants: bool,
 }
 
 impl Default for ScriptConfig {
     fn default() -> Self {
         Self {
             source_pattern: "src/**/*.{js,jsx,ts,tsx}".to_string(),
-            translation_import_source: "i18nexus".to_string(),
+            translation_import_source: StringConstants::DEFAULT_TRANSLATION_IMPORT_SOURCE.to_string(),
             mode: None,
             framework: None,
             server_translation_function: Some("getTranslations".to_string()),
+            rich_text: None,
+            source_scripts: None,
+            source_text_pattern: None,
+            natural_language_threshold: None,
+            key_strategy: None,
+            key_prefix: false,
+            counter_suffixes: None,
+            counter_names: None,
+            josa_hints: false,
+            non_component_strategy: None,
+            i18n_instance_source: None,
+            lazy_translation_function: None,
+            lazy_translation_source: None,
+            defer_constants: false,
         }
     }
 }
@@ -1,4 +1,4 @@
-use t_wrapper_rust::{wrap_translations, ScriptConfig};
+use t_wrapper_rust::{wrap_translations, ScriptConfig, SkipReason};
 use t_wrapper_rust::utils::constants::{ConsoleMessages, CliOptions, CliHelp};
 use std::env;
 
@@ -33,6 +33,16 @@ fn main() {
     }
 
     if let Ok(result) = wrap_translations(Some(config)) {
+        for file in &result.skipped {
+            for skipped in &file.skipped {
+                if skipped.reason == SkipReason::NoComponentScope {
+                    eprintln!(
+                        "{}",
+                        ConsoleMessages::no_component_scope(&file.file, skipped.line, &skipped.value)
+                    );
+                }
+            }
+        }
         let time_in_seconds = result.total_time_ms as f64 / 1000.0;
         println!(
             "✅ Processed {} file(s) in {:.2}s",
@@ -40,7 +50,7 @@ fn main() {
             time_in_seconds
         );
     } else {
-        eprintln!("{} {}", ConsoleMessages::FATAL_ERROR, "Error occurred");
+        eprintln!("{} Error occurred", ConsoleMessages::FATAL_ERROR);
         std::process::exit(1);
     }
 }
@@ -1,8 +1,9 @@
-/// Import 관리 유틸리티
+//! Import 관리 유틸리티
 
-use crate::utils::constants::StringConstants;
+use crate::ast::ast_helpers::directive_prologue;
+use crate::ast::translation_scope::TranslationCallees;
+use crate::utils::constants::{KeyStrategies, NonComponentStrategies, StringConstants};
 use swc_ecma_ast::*;
-use swc_ecma_visit::{VisitMut, VisitMutWith};
 use swc_common::DUMMY_SP;
 
 /// useTranslation 훅을 생성하는 AST 노드 생성
@@ -10,6 +11,159 @@ use swc_common::DUMMY_SP;
 /// TypeScript 버전과 동일한 로직:
 /// const { t } = useTranslation();
 pub fn create_use_translation_hook() -> Stmt {
+    create_use_translation_hook_with_options(None, None)
+}
+
+/// 네임스페이스와 keyPrefix 옵션을 받는 useTranslation 훅 생성
+///
+/// - `useTranslation("common")`
+/// - `useTranslation("common", { keyPrefix: "LoginForm" })`
+/// - 네임스페이스 없이 keyPrefix만 있으면 `useTranslation(undefined, { keyPrefix: "LoginForm" })`
+pub fn create_use_translation_hook_with_options(namespace: Option<&str>, key_prefix: Option<&str>) -> Stmt {
+    create_translation_binding_with_callee(
+        create_ident_expr(StringConstants::USE_TRANSLATION),
+        StringConstants::TRANSLATION_FUNCTION,
+        false,
+        namespace,
+        key_prefix,
+    )
+}
+
+/// 서버 컴포넌트용 번역 바인딩 생성
+///
+/// TypeScript 버전(createTranslationBinding의 server 모드)과 동일한 로직:
+/// const { t } = await getTranslations();
+/// 인자 규칙은 useTranslation 훅과 같음
+pub fn create_server_translation_binding(
+    server_function_name: &str,
+    namespace: Option<&str>,
+    key_prefix: Option<&str>,
+) -> Stmt {
+    create_translation_binding_with_callee(
+        create_ident_expr(server_function_name),
+        StringConstants::TRANSLATION_FUNCTION,
+        true,
+        namespace,
+        key_prefix,
+    )
+}
+
+/// 호출 대상을 지정한 번역 바인딩 생성
+///
+/// callee는 translation_callee로 얻은 지역 참조 (useT, i18n.useTranslation 등)
+/// binding_name이 t가 아니면 이름을 바꿔 구조 분해: const { t: tr } = callee(...)
+/// - await_call이 false면 const { t } = callee(...)
+/// - await_call이 true면 const { t } = await callee(...)
+pub fn create_translation_binding_with_callee(
+    callee: Expr,
+    binding_name: &str,
+    await_call: bool,
+    namespace: Option<&str>,
+    key_prefix: Option<&str>,
+) -> Stmt {
+    let call = Expr::Call(create_translation_call(callee, namespace, key_prefix));
+    if await_call {
+        create_translation_binding(
+            Expr::Await(AwaitExpr {
+                span: DUMMY_SP,
+                arg: Box::new(call),
+            }),
+            binding_name,
+        )
+    } else {
+        create_translation_binding(call, binding_name)
+    }
+}
+
+fn create_ident_expr(name: &str) -> Expr {
+    Expr::Ident(create_ident(name))
+}
+
+fn create_ident(name: &str) -> Ident {
+    Ident {
+        span: DUMMY_SP,
+        sym: name.into(),
+        optional: false,
+        ctxt: Default::default(),
+    }
+}
+
+/// 번역 함수 호출 생성: callee(namespace?, { keyPrefix }?)
+fn create_translation_call(callee: Expr, namespace: Option<&str>, key_prefix: Option<&str>) -> CallExpr {
+    let mut args = Vec::new();
+    if namespace.is_some() || key_prefix.is_some() {
+        let namespace_expr = match namespace {
+            Some(namespace) => Expr::Lit(Lit::Str(Str {
+                span: DUMMY_SP,
+                value: namespace.into(),
+                raw: None,
+            })),
+            None => Expr::Ident(Ident {
+                span: DUMMY_SP,
+                sym: StringConstants::UNDEFINED.into(),
+                optional: false,
+                ctxt: Default::default(),
+            }),
+        };
+        args.push(ExprOrSpread {
+            spread: None,
+            expr: Box::new(namespace_expr),
+        });
+    }
+    if let Some(key_prefix) = key_prefix {
+        args.push(ExprOrSpread {
+            spread: None,
+            expr: Box::new(Expr::Object(ObjectLit {
+                span: DUMMY_SP,
+                props: vec![PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                    key: PropName::Ident(IdentName {
+                        sym: KeyStrategies::KEY_PREFIX.into(),
+                        span: DUMMY_SP,
+                    }),
+                    value: Box::new(Expr::Lit(Lit::Str(Str {
+                        span: DUMMY_SP,
+                        value: key_prefix.into(),
+                        raw: None,
+                    }))),
+                })))],
+            })),
+        });
+    }
+
+    CallExpr {
+        span: DUMMY_SP,
+        callee: Callee::Expr(Box::new(callee)),
+        args,
+        type_args: None,
+        ctxt: Default::default(),
+    }
+}
+
+/// const { t } = init; (binding_name이 t가 아니면 const { t: binding_name } = init;)
+fn create_translation_binding(init: Expr, binding_name: &str) -> Stmt {
+    let prop = if binding_name == StringConstants::TRANSLATION_FUNCTION {
+        // shorthand: { t }
+        ObjectPatProp::Assign(AssignPatProp {
+            span: DUMMY_SP,
+            key: BindingIdent {
+                id: create_ident(StringConstants::TRANSLATION_FUNCTION),
+                type_ann: None,
+            },
+            value: None,
+        })
+    } else {
+        ObjectPatProp::KeyValue(KeyValuePatProp {
+            key: PropName::Ident(IdentName {
+                span: DUMMY_SP,
+                sym: StringConstants::TRANSLATION_FUNCTION.into(),
+            }),
+            value: Box::new(Pat::Ident(BindingIdent {
+                id: create_ident(binding_name),
+                type_ann: None,
+            })),
+        })
+    };
+
     Stmt::Decl(Decl::Var(Box::new(VarDecl {
         span: DUMMY_SP,
         kind: VarDeclKind::Const,
@@ -18,131 +172,306 @@ pub fn create_use_translation_hook() -> Stmt {
             span: DUMMY_SP,
             name: Pat::Object(ObjectPat {
                 span: DUMMY_SP,
-                props: vec![ObjectPatProp::KeyValue(KeyValuePatProp {
-                    key: PropName::Ident(IdentName {
-                        sym: StringConstants::TRANSLATION_FUNCTION.into(),
-                        span: DUMMY_SP,
-                    }),
-                    value: Box::new(Pat::Ident(BindingIdent {
-                        id: Ident {
-                            span: DUMMY_SP,
-                            sym: StringConstants::TRANSLATION_FUNCTION.into(),
-                            optional: false,
-                            ctxt: Default::default(),
-                        },
-                        type_ann: None,
-                    })),
-                })],
+                props: vec![prop],
                 optional: false,
                 type_ann: None,
             }),
-            init: Some(Box::new(Expr::Call(CallExpr {
-                span: DUMMY_SP,
-                callee: Callee::Expr(Box::new(Expr::Ident(Ident {
-                    span: DUMMY_SP,
-                    sym: StringConstants::USE_TRANSLATION.into(),
-                    optional: false,
-                    ctxt: Default::default(),
-                }))),
-                args: vec![],
-                type_args: None,
-                ctxt: Default::default(),
-            }))),
+            init: Some(Box::new(init)),
             definite: false,
         }],
         ctxt: Default::default(),
     })))
 }
 
+/// 'use client' 디렉티브 추가
+///
+/// TypeScript 버전(ensureUseClientDirective)과 동일한 로직:
+/// 1. 디렉티브 프롤로그에 'use client'가 이미 있으면 그대로 둠
+/// 2. 'use server' 파일에는 추가하지 않음
+/// 3. 없으면 모듈 맨 위(import보다 위, shebang 아래)에 추가
+///
+/// 반환값: AST가 수정되었는지 여부
+pub fn ensure_use_client_directive(module: &mut Module) -> bool {
+    let has_directive = |directive: &str| {
+        directive_prologue(module).any(|value| value == directive)
+    };
+    if has_directive(StringConstants::USE_CLIENT_DIRECTIVE)
+        || has_directive(StringConstants::USE_SERVER_DIRECTIVE)
+    {
+        return false;
+    }
+
+    // shebang은 Module.shebang에 따로 보관되므로 body 맨 앞이 곧 shebang 아래
+    module.body.insert(
+        0,
+        ModuleItem::Stmt(Stmt::Expr(ExprStmt {
+            span: DUMMY_SP,
+            expr: Box::new(Expr::Lit(Lit::Str(Str {
+                span: DUMMY_SP,
+                value: StringConstants::USE_CLIENT_DIRECTIVE.into(),
+                raw: Some(format!("'{}'", StringConstants::USE_CLIENT_DIRECTIVE).into()),
+            }))),
+        })),
+    );
+    true
+}
+
 /// AST에 useTranslation import가 필요한지 확인하고 추가
 /// 
-/// TypeScript 버전과 동일한 로직:
-/// 1. AST를 traverse하여 ImportDeclaration 확인
-/// 2. 같은 소스의 import가 있고 useTranslation이 있으면 false 반환
-/// 3. 같은 소스의 import가 있지만 useTranslation이 없으면 specifier 추가
-/// 4. import가 없으면 새로 생성
+/// TypeScript 버전과 동일한 로직 (ensure_named_import 참고):
+/// 1. 같은 소스에서 useTranslation을 이미 가져오면 (별칭, import * as 포함) false 반환
+/// 2. 같은 소스의 import가 있지만 useTranslation이 없으면 specifier 추가
+/// 3. import가 없으면 새로 생성
 pub fn add_import_if_needed(module: &mut Module, translation_import_source: &str) -> bool {
-    let mut has_import = false;
-    let mut has_use_translation = false;
-
-    // TODO: SWC AST traverse로 구현
-    // module.visit_mut_with(&mut ImportVisitor {
-    //     translation_import_source,
-    //     has_import: &mut has_import,
-    //     has_use_translation: &mut has_use_translation,
-    // });
-
-    // 기존 import 확인
-    // TODO: Wtf8Atom을 문자열로 변환하는 올바른 방법 찾기
-    for stmt in &module.body {
-        // TODO: import 확인 로직 구현
-        // if let ModuleItem::ModuleDecl(ModuleDecl::Import(import_decl)) = stmt {
-        //     // Wtf8Atom 처리 필요
-        //     // if import_decl.src.value == translation_import_source {
-        //     //     has_import = true;
-        //     //     // specifiers 확인
-        //     //     for spec in &import_decl.specifiers {
-        //     //         if let ImportSpecifier::Named(named) = spec {
-        //     //             if named.local.sym == StringConstants::USE_TRANSLATION {
-        //     //                 has_use_translation = true;
-        //     //             }
-        //     //         }
-        //     //     }
-        //     // }
-        // }
-        let _ = stmt;
+    ensure_named_import(module, translation_import_source, StringConstants::USE_TRANSLATION)
+}
+
+/// Server translation function import 추가
+/// TypeScript 버전과 동일하게 translation_import_source + "/server"에서 가져옴
+/// 예: import { getTranslations } from "i18nexus/server";
+///
+/// 반환값: AST가 수정되었는지 여부
+pub fn add_server_translation_import(
+    module: &mut Module,
+    translation_import_source: &str,
+    server_function_name: &str,
+) -> bool {
+    ensure_named_import(
+        module,
+        &server_import_source(translation_import_source),
+        server_function_name,
+    )
+}
+
+/// 서버 번역 함수를 가져오는 소스: "i18nexus" → "i18nexus/server"
+pub fn server_import_source(translation_import_source: &str) -> String {
+    format!("{}{}", translation_import_source, StringConstants::SERVER_IMPORT_SUFFIX)
+}
+
+/// AST에 named import가 필요한지 확인하고 추가
+///
+/// TypeScript 버전(ensureNamedImport)과 동일한 로직:
+/// 1. 같은 소스에서 이미 가져오고 있으면 그대로 둠
+///    (import { name as alias }, import * as ns 포함, import type은 제외)
+/// 2. 같은 소스의 값 import가 있으면 specifier 추가
+/// 3. import가 없으면 새로 생성하여 디렉티브와 마지막 import 뒤에 추가
+///
+/// 반환값: AST가 수정되었는지 여부
+pub fn ensure_named_import(module: &mut Module, source: &str, imported_name: &str) -> bool {
+    if find_imported_binding(module, source, imported_name).is_some() {
+        return false;
     }
 
-    // useTranslation이 없으면 추가
-    if has_import && !has_use_translation {
-        // TODO: 기존 import에 specifier 추가
-        // if let Some(import_decl) = find_import_declaration(module, translation_import_source) {
-        //     import_decl.specifiers.push(ImportSpecifier::Named(NamedImportSpecifier {
-        //         local: Ident {
-        //             sym: StringConstants::USE_TRANSLATION.into(),
-        //             ...
-        //         },
-        //         imported: None,
-        //         ...
-        //     }));
-        //     return true;
-        // }
+    let mergeable = module.body.iter_mut().find_map(|item| match item {
+        ModuleItem::ModuleDecl(ModuleDecl::Import(import_decl))
+            if is_value_import_from(import_decl, source)
+                && !import_decl
+                    .specifiers
+                    .iter()
+                    .any(|spec| matches!(spec, ImportSpecifier::Namespace(_))) =>
+        {
+            Some(import_decl)
+        }
+        _ => None,
+    });
+    if let Some(import_decl) = mergeable {
+        import_decl.specifiers.push(create_named_specifier(imported_name));
         return true;
     }
 
-    // import가 아예 없으면 새로 생성
-    if !has_import {
-        // TODO: 새 import declaration 생성 및 추가
-        // let import_decl = ImportDecl {
-        //     src: Box::new(Str {
-        //         value: translation_import_source.into(),
-        //         ...
-        //     }),
-        //     specifiers: vec![ImportSpecifier::Named(NamedImportSpecifier {
-        //         local: Ident {
-        //             sym: StringConstants::USE_TRANSLATION.into(),
-        //             ...
-        //         },
-        //         imported: None,
-        //         ...
-        //     })],
-        //     ...
-        // };
-        // module.body.insert(0, ModuleItem::ModuleDecl(ModuleDecl::Import(import_decl)));
-        return true;
+    let index = import_insertion_index(module);
+    module.body.insert(
+        index,
+        ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+            span: DUMMY_SP,
+            specifiers: vec![create_named_specifier(imported_name)],
+            src: Box::new(Str {
+                span: DUMMY_SP,
+                value: source.into(),
+                raw: None,
+            }),
+            type_only: false,
+            with: None,
+            phase: Default::default(),
+        })),
+    );
+    true
+}
+
+/// AST에 default import가 필요한지 확인하고 추가
+///
+/// 1. 같은 소스의 default import가 있으면 그대로 둠 (지역 이름은 find_default_import로 확인)
+/// 2. 없으면 import local_name from source를 디렉티브와 마지막 import 뒤에 추가
+///
+/// 반환값: AST가 수정되었는지 여부
+pub fn ensure_default_import(module: &mut Module, source: &str, local_name: &str) -> bool {
+    if find_default_import(module, source).is_some() {
+        return false;
     }
 
-    false
+    let index = import_insertion_index(module);
+    module.body.insert(
+        index,
+        ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+            span: DUMMY_SP,
+            specifiers: vec![ImportSpecifier::Default(ImportDefaultSpecifier {
+                span: DUMMY_SP,
+                local: create_ident(local_name),
+            })],
+            src: Box::new(Str {
+                span: DUMMY_SP,
+                value: source.into(),
+                raw: None,
+            }),
+            type_only: false,
+            with: None,
+            phase: Default::default(),
+        })),
+    );
+    true
+}
+
+/// source의 default import 지역 이름 (import type은 무시)
+/// import i18next from "@/lib/i18n" → "i18next"
+pub fn find_default_import(module: &Module, source: &str) -> Option<String> {
+    module.body.iter().find_map(|item| {
+        let ModuleItem::ModuleDecl(ModuleDecl::Import(import_decl)) = item else {
+            return None;
+        };
+        if !is_value_import_from(import_decl, source) {
+            return None;
+        }
+        import_decl.specifiers.iter().find_map(|spec| match spec {
+            ImportSpecifier::Default(default) => Some(default.local.sym.to_string()),
+            _ => None,
+        })
+    })
 }
 
-/// Server translation function import 추가
-pub fn add_server_translation_import(
-    module: &mut Module,
+/// 기존 import가 imported_name을 어떤 이름으로 가져오는지
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ImportedBinding {
+    /// import { useTranslation } / import { useTranslation as useT } → 지역 이름
+    Named(String),
+    /// import * as i18n → 네임스페이스 이름 (i18n.useTranslation으로 호출)
+    Namespace(String),
+}
+
+/// source에서 imported_name을 가져오는 값 import 찾기 (import type은 무시)
+pub fn find_imported_binding(
+    module: &Module,
+    source: &str,
+    imported_name: &str,
+) -> Option<ImportedBinding> {
+    module.body.iter().find_map(|item| {
+        let ModuleItem::ModuleDecl(ModuleDecl::Import(import_decl)) = item else {
+            return None;
+        };
+        if !is_value_import_from(import_decl, source) {
+            return None;
+        }
+        import_decl.specifiers.iter().find_map(|spec| match spec {
+            ImportSpecifier::Named(named)
+                if !named.is_type_only && imported_name_of(named) == imported_name =>
+            {
+                Some(ImportedBinding::Named(named.local.sym.to_string()))
+            }
+            ImportSpecifier::Namespace(namespace) => {
+                Some(ImportedBinding::Namespace(namespace.local.sym.to_string()))
+            }
+            _ => None,
+        })
+    })
+}
+
+/// imported_name을 호출할 때 쓸 표현식
+///
+/// 기존 import의 별칭이나 네임스페이스를 따르고, import가 없으면 imported_name 그대로
+/// - import { useTranslation as useT } → useT
+/// - import * as i18n → i18n.useTranslation
+pub fn translation_callee(module: &Module, source: &str, imported_name: &str) -> Expr {
+    match find_imported_binding(module, source, imported_name) {
+        Some(ImportedBinding::Named(local)) => create_ident_expr(&local),
+        Some(ImportedBinding::Namespace(namespace)) => Expr::Member(MemberExpr {
+            span: DUMMY_SP,
+            obj: Box::new(create_ident_expr(&namespace)),
+            prop: MemberProp::Ident(IdentName {
+                span: DUMMY_SP,
+                sym: imported_name.into(),
+            }),
+        }),
+        None => create_ident_expr(imported_name),
+    }
+}
+
+/// 파일에서 기존 번역 바인딩으로 인정할 번역 훅/서버 번역 함수의 callee
+/// 서버 번역 함수는 "<translation_import_source>/server"에서 찾음
+pub fn translation_callees(
+    module: &Module,
     translation_import_source: &str,
-    server_function_name: &str,
-) -> bool {
-    // TODO: server translation function import 추가
-    // addImportIfNeeded와 유사한 로직이지만 server_function_name을 사용
-    let _ = (module, translation_import_source, server_function_name);
-    true
+    server_translation_function: &str,
+) -> TranslationCallees {
+    TranslationCallees {
+        hook: translation_callee(module, translation_import_source, StringConstants::USE_TRANSLATION),
+        server: translation_callee(
+            module,
+            &server_import_source(translation_import_source),
+            server_translation_function,
+        ),
+    }
+}
+
+/// 전역 i18n 인스턴스의 번역 함수: i18n.t
+/// source의 default import가 이미 있으면 그 이름을 씀 (import i18next from "..." → i18next.t)
+pub fn i18n_instance_callee(module: &Module, source: &str) -> Expr {
+    let instance = find_default_import(module, source)
+        .unwrap_or_else(|| NonComponentStrategies::I18N_INSTANCE.to_string());
+    Expr::Member(MemberExpr {
+        span: DUMMY_SP,
+        obj: Box::new(create_ident_expr(&instance)),
+        prop: MemberProp::Ident(IdentName {
+            span: DUMMY_SP,
+            sym: StringConstants::TRANSLATION_FUNCTION.into(),
+        }),
+    })
+}
+
+/// source에서 가져오는 값 import인지 (import type { ... } 제외)
+fn is_value_import_from(import_decl: &ImportDecl, source: &str) -> bool {
+    !import_decl.type_only && import_decl.src.value.to_string_lossy() == source
+}
+
+/// 새 import를 넣을 위치: 마지막 import 다음, import가 없으면 디렉티브 프롤로그 다음
+fn import_insertion_index(module: &Module) -> usize {
+    module
+        .body
+        .iter()
+        .rposition(|item| matches!(item, ModuleItem::ModuleDecl(ModuleDecl::Import(_))))
+        .map(|index| index + 1)
+        .unwrap_or_else(|| directive_prologue(module).count())
+}
+
+/// named import specifier가 가져오는 원래 이름
+/// import { a as b } → "a", import { a } → "a"
+fn imported_name_of(named: &ImportNamedSpecifier) -> String {
+    match &named.imported {
+        Some(ModuleExportName::Ident(ident)) => ident.sym.to_string(),
+        Some(ModuleExportName::Str(str_lit)) => str_lit.value.to_string_lossy().to_string(),
+        None => named.local.sym.to_string(),
+    }
+}
+
+/// import { name } specifier 생성
+fn create_named_specifier(name: &str) -> ImportSpecifier {
+    ImportSpecifier::Named(ImportNamedSpecifier {
+        span: DUMMY_SP,
+        local: Ident {
+            span: DUMMY_SP,
+            sym: name.into(),
+            optional: false,
+            ctxt: Default::default(),
+        },
+        imported: None,
+        is_type_only: false,
+    })
 }
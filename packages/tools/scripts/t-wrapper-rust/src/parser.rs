@@ -1,7 +1,6 @@
-/// SWC 파서 모듈
-/// TypeScript/JavaScript 파일을 AST로 파싱
+//! SWC 파서 모듈
+//! TypeScript/JavaScript 파일을 AST로 파싱
 
-use std::sync::Arc;
 use swc_common::{
     errors::Handler,
     FileName, SourceMap, GLOBALS, sync::Lrc,
@@ -31,8 +30,19 @@ impl Default for ParseOptions {
 /// 
 /// SWC 코드 생성 API를 사용하여 AST를 JavaScript/TypeScript 코드로 변환합니다.
 pub fn generate_code(module: &Module) -> Result<String> {
+    generate_code_with_comments(module, None)
+}
+
+/// 주석을 포함하여 AST를 코드로 변환
+///
+/// parse_file_with_comments로 얻은 주석을 넘기면 i18n-ignore 같은 주석이 출력에 유지되어
+/// 다시 실행해도 같은 결과가 나옵니다.
+pub fn generate_code_with_comments(
+    module: &Module,
+    comments: Option<&SingleThreadedComments>,
+) -> Result<String> {
     use swc_ecma_codegen::{text_writer::JsWriter, Emitter};
-    use swc_common::{SourceMap, sync::Lrc};
+    use swc_common::comments::Comments;
     
     let cm: Lrc<SourceMap> = Default::default();
     let mut buf = Vec::new();
@@ -41,7 +51,7 @@ pub fn generate_code(module: &Module) -> Result<String> {
     let mut emitter = Emitter {
         cfg: swc_ecma_codegen::Config::default(),
         cm: cm.clone(),
-        comments: None,
+        comments: comments.map(|c| c as &dyn Comments),
         wr: writer,
     };
     
@@ -57,7 +67,18 @@ pub fn generate_code(module: &Module) -> Result<String> {
 /// SWC 저수준 API를 직접 사용하여 파싱합니다.
 /// GLOBALS.set 패턴을 사용해야 합니다.
 pub fn parse_file(code: &str, options: ParseOptions) -> Result<Module> {
-    let cm = Arc::new(SourceMap::default());
+    parse_file_with_comments(code, options).map(|(module, _)| module)
+}
+
+/// 파일을 AST로 파싱하고 주석도 함께 반환
+///
+/// 주석의 위치(BytePos)는 반환된 Module의 span과 같은 기준을 사용하므로
+/// 노드의 leading comments를 찾을 수 있습니다.
+pub fn parse_file_with_comments(
+    code: &str,
+    options: ParseOptions,
+) -> Result<(Module, SingleThreadedComments)> {
+    let cm: Lrc<SourceMap> = Default::default();
     let emitter = Box::new(swc_common::errors::emitter::EmitterWriter::new(
         Box::new(std::io::stderr()),
         None,
@@ -103,5 +124,6 @@ pub fn parse_file(code: &str, options: ParseOptions) -> Result<Module> {
                 anyhow::anyhow!(msg)
             })
             .context("Failed to parse file")
+            .map(|module| (module, comments))
     })
 }
@@ -1,5 +1,5 @@
-/// t-wrapper 상수 정의
-/// 모든 상수를 중앙화
+//! t-wrapper 상수 정의
+//! 모든 상수를 중앙화
 
 use std::sync::LazyLock;
 use regex::Regex;
@@ -13,6 +13,14 @@ impl ConsoleMessages {
     }
 
     pub const FATAL_ERROR: &'static str = "❌ Fatal error:";
+
+    /// 컴포넌트 밖이라 t를 바인딩할 수 없어 변환하지 않은 문자열
+    pub fn no_component_scope(file_path: &str, line: Option<usize>, value: &str) -> String {
+        match line {
+            Some(line) => format!("⚠️ {}:{} no component scope for \"{}\"", file_path, line, value),
+            None => format!("⚠️ {} no component scope for \"{}\"", file_path, value),
+        }
+    }
 }
 
 /// CLI 옵션
@@ -42,6 +50,149 @@ impl CliHelp {
   t-wrapper --dry-run";
 }
 
+/// 컴포넌트가 없는 모듈(utils, API 클라이언트, 상수 파일)의 문자열 처리 방식 상수
+pub struct NonComponentStrategies;
+
+impl NonComponentStrategies {
+    /// 변환하지 않고 보고만 함 (기본값)
+    pub const SKIP: &'static str = "skip";
+    /// 전역 i18n 인스턴스로 변환: i18n.t("...")
+    pub const GLOBAL: &'static str = "global";
+    /// 지연 번역 마커로 감쌈: tKey("...") → 렌더링 시점에 t()로 번역
+    pub const LAZY: &'static str = "lazy";
+    /// 전역 i18n 인스턴스를 default export하는 모듈
+    pub const DEFAULT_I18N_INSTANCE_SOURCE: &'static str = "@/lib/i18n";
+    /// 전역 i18n 인스턴스의 지역 이름 (import i18n from "...")
+    pub const I18N_INSTANCE: &'static str = "i18n";
+    /// lazy_translation_source에서 가져오는 지연 번역 마커 함수의 기본 이름
+    pub const LAZY_TRANSLATION_FUNCTION: &'static str = "tKey";
+}
+
+/// 번역 키 생성 전략 상수
+pub struct KeyStrategies;
+
+impl KeyStrategies {
+    pub const SOURCE: &'static str = "source";
+    pub const HASH: &'static str = "hash";
+    pub const SLUG: &'static str = "slug";
+    pub const SEMANTIC: &'static str = "semantic";
+    /// 원문이 키가 아닐 때 원문을 담는 t() 옵션
+    pub const DEFAULT_VALUE: &'static str = "defaultValue";
+    /// 이 글자 수를 넘는 텍스트는 원문 기반 키 대신 해시 키 사용
+    pub const MAX_TEXT_KEY_LENGTH: usize = 80;
+    /// 해시 키 길이 (16진수 자릿수)
+    pub const HASH_LENGTH: usize = 12;
+    /// semantic 키 조각 구분자 (i18next 중첩 키)
+    pub const SEGMENT_SEPARATOR: &'static str = ".";
+    /// semantic 키에서 엘리먼트를 구분하는 속성 (우선순위 순)
+    pub const QUALIFIER_ATTRIBUTES: [&'static str; 3] = ["id", "name", "type"];
+    /// useTranslation 옵션의 keyPrefix
+    pub const KEY_PREFIX: &'static str = "keyPrefix";
+}
+
+/// 복수형(count) 감지 상수
+pub struct Pluralization;
+
+impl Pluralization {
+    /// 숫자 뒤에 붙는 한국어 단위 명사 (긴 것부터 확인: 개월 → 개)
+    pub const COUNTER_SUFFIXES: [&'static str; 5] = ["개월", "개", "명", "건", "번"];
+    /// 개수를 나타내는 변수/프로퍼티 이름
+    pub const COUNTER_NAMES: [&'static str; 4] = ["count", "n", "total", "length"];
+    /// i18next가 복수형 접미사(_one, _other)를 고르는 interpolation 변수
+    pub const COUNT_VARIABLE: &'static str = "count";
+}
+
+/// 숫자/통화/날짜 interpolation 포맷 상수 (i18next 내장 formatter)
+pub struct Formatting;
+
+impl Formatting {
+    pub const NUMBER: &'static str = "number";
+    pub const DATETIME: &'static str = "datetime";
+    pub const CURRENCY: &'static str = "currency";
+    /// placeholder의 변수명과 포맷 구분자: {{price, currency(KRW)}}
+    pub const FORMAT_SEPARATOR: &'static str = ", ";
+    /// 숫자 뒤에 붙는 통화 단위와 ISO 4217 코드
+    pub const CURRENCY_SUFFIXES: [(&'static str, &'static str); 2] = [("원", "KRW"), ("달러", "USD")];
+    pub const LOCALE_METHOD_PREFIX: &'static str = "toLocale";
+    pub const TO_LOCALE_STRING: &'static str = "toLocaleString";
+    pub const TO_LOCALE_DATE_STRING: &'static str = "toLocaleDateString";
+    pub const INTL: &'static str = "Intl";
+    pub const NUMBER_FORMAT: &'static str = "NumberFormat";
+    pub const DATE_TIME_FORMAT: &'static str = "DateTimeFormat";
+    pub const FORMAT_METHOD: &'static str = "format";
+    pub const STYLE_OPTION: &'static str = "style";
+    pub const CURRENCY_OPTION: &'static str = "currency";
+    /// 날짜의 한 부분을 꺼내는 Date getter
+    pub const DATE_GETTERS: [&'static str; 6] = [
+        "getFullYear",
+        "getMonth",
+        "getDate",
+        "getHours",
+        "getMinutes",
+        "getSeconds",
+    ];
+    /// 날짜 부분 뒤에 붙는 단위
+    pub const DATE_UNITS: [char; 6] = ['년', '월', '일', '시', '분', '초'];
+    /// 날짜 부분 사이에 올 수 있는 구분 문자 (단위, 공백 외)
+    pub const DATE_SEPARATORS: [char; 4] = ['.', '-', '/', ':'];
+}
+
+/// 조사(josa) 감지 상수
+pub struct Josa;
+
+impl Josa {
+    /// i18next 커스텀 formatter 이름: {{name, josa(을/를)}}
+    pub const FORMAT: &'static str = "josa";
+    /// interpolation 바로 뒤의 조사 표기 → 받침 유무에 따라 고르는 조사 쌍 (긴 표기부터 확인)
+    pub const PARTICLES: [(&'static str, &'static str); 19] = [
+        ("을(를)", "을/를"),
+        ("를(을)", "을/를"),
+        ("이(가)", "이/가"),
+        ("가(이)", "이/가"),
+        ("은(는)", "은/는"),
+        ("는(은)", "은/는"),
+        ("와(과)", "와/과"),
+        ("과(와)", "와/과"),
+        ("(으)로", "으로/로"),
+        ("으로", "으로/로"),
+        ("을", "을/를"),
+        ("를", "을/를"),
+        ("이", "이/가"),
+        ("가", "이/가"),
+        ("은", "은/는"),
+        ("는", "은/는"),
+        ("와", "와/과"),
+        ("과", "와/과"),
+        ("로", "으로/로"),
+    ];
+}
+
+/// 자연어(영어 등) 하드코딩 문자열 감지 설정
+pub struct NaturalLanguage;
+
+impl NaturalLanguage {
+    /// 권장 기본 임계값: 이 점수 이상이면 t()로 변환
+    pub const DEFAULT_THRESHOLD: f64 = 0.5;
+    /// 임계값보다 이만큼 낮은 점수까지는 변환하지 않고 보고만 함
+    pub const BORDERLINE_MARGIN: f64 = 0.2;
+    /// 단어 수에 따른 기본 점수: 한 단어, 두 단어, 세 단어 이상
+    pub const SINGLE_WORD_SCORE: f64 = 0.1;
+    pub const TWO_WORDS_SCORE: f64 = 0.3;
+    pub const MANY_WORDS_SCORE: f64 = 0.45;
+    /// 단어 사이 공백이 있을 때 가산점
+    pub const WORD_SPACING_WEIGHT: f64 = 0.1;
+    /// 문장 부호(. ! ? : … 또는 ", ")가 있을 때 가산점
+    pub const PUNCTUATION_WEIGHT: f64 = 0.15;
+    /// 문장형 대문자("Save changes")일 때 가산점
+    pub const SENTENCE_CASE_WEIGHT: f64 = 0.15;
+    /// 글자(+공백) 비율이 MIN_LETTER_RATIO 이상이면 가산점, 미만이면 감점
+    pub const MIN_LETTER_RATIO: f64 = 0.7;
+    pub const LETTER_RATIO_WEIGHT: f64 = 0.1;
+    pub const LETTER_RATIO_PENALTY: f64 = 0.2;
+    /// JSX 텍스트나 번역 대상 속성 문맥일 때 가산점
+    pub const UI_CONTEXT_WEIGHT: f64 = 0.3;
+}
+
 /// 문자열 상수
 pub struct StringConstants;
 
@@ -50,9 +201,42 @@ impl StringConstants {
     pub const I18N_IGNORE_COMMENT: &'static str = "// i18n-ignore";
     pub const I18N_IGNORE_BLOCK: &'static str = "/* i18n-ignore";
     pub const I18N_IGNORE_JSX: &'static str = "{/* i18n-ignore";
+    pub const I18N_IGNORE_NEXT_LINE: &'static str = "i18n-ignore-next-line";
+    pub const I18N_IGNORE_START: &'static str = "i18n-ignore-start";
+    pub const I18N_IGNORE_END: &'static str = "i18n-ignore-end";
+    pub const I18N_IGNORE_FILE: &'static str = "i18n-ignore-file";
     pub const TRANSLATION_FUNCTION: &'static str = "t";
+    /// 컴포넌트 안에 t라는 다른 바인딩이 있을 때 쓸 번역 함수 이름 (모두 쓰이고 있으면 t2, t3, ...)
+    pub const TRANSLATION_FUNCTION_FALLBACKS: &'static [&'static str] = &["tr"];
     pub const USE_TRANSLATION: &'static str = "useTranslation";
+    /// useTranslation을 import하는 기본 모듈 (ScriptConfig::translation_import_source 기본값)
+    pub const DEFAULT_TRANSLATION_IMPORT_SOURCE: &'static str = "i18nexus";
+    /// namespace 없이 옵션만 넘길 때 첫 번째 인자: useTranslation(undefined, { keyPrefix })
+    pub const UNDEFINED: &'static str = "undefined";
+    /// CommonJS 모듈 로드 함수 (인자는 모듈 경로)
+    pub const REQUIRE: &'static str = "require";
+    /// 컴포넌트 함수를 감싸는 React API: const Button = memo(() => ...)
+    pub const COMPONENT_WRAPPERS: &'static [&'static str] = &["memo", "forwardRef"];
+    /// 이름 없는 export default 컴포넌트의 이름: export default function () {}, export default () => ...
+    /// default는 예약어라 실제 컴포넌트 이름과 겹치지 않음
+    pub const DEFAULT_EXPORT_COMPONENT: &'static str = "default";
+    /// 첫 번째 콜백 인자로 배열 원소를 받는 메서드: OPTIONS.map((option) => ...)
+    pub const ARRAY_ITERATION_METHODS: &'static [&'static str] =
+        &["map", "forEach", "filter", "find", "flatMap", "some", "every"];
+    /// 객체 값 배열을 만드는 정적 메서드: Object.values(STATUS_LABELS)
+    pub const OBJECT_VALUES: (&'static str, &'static str) = ("Object", "values");
     pub const GET_SERVER_TRANSLATION: &'static str = "getTranslations";
+    /// 서버 번역 함수는 translation_import_source + "/server"에서 import
+    pub const SERVER_IMPORT_SUFFIX: &'static str = "/server";
+    pub const MODE_SERVER: &'static str = "server";
+    pub const MODE_CLIENT: &'static str = "client";
+    pub const FRAMEWORK_NEXTJS: &'static str = "nextjs";
+    pub const USE_CLIENT_DIRECTIVE: &'static str = "use client";
+    pub const USE_SERVER_DIRECTIVE: &'static str = "use server";
+    /// Next.js App Router 디렉토리 이름 (기본이 서버 컴포넌트)
+    pub const APP_ROUTER_DIR: &'static str = "app";
+    /// Next.js Pages Router 디렉토리 이름 (클라이언트 컴포넌트)
+    pub const PAGES_ROUTER_DIR: &'static str = "pages";
     pub const COMPLETION_TITLE: &'static str = "Translation Wrapper Completed";
     pub const DEFAULT_ENV: &'static str = "production";
     pub const VARIABLE_KIND: &'static str = "const";
@@ -60,6 +244,36 @@ impl StringConstants {
     pub const INTERPOLATION_START: &'static str = "{{";
     pub const INTERPOLATION_END: &'static str = "}}";
     pub const MEMBER_SEPARATOR: &'static str = "_";
+    pub const TRANS_COMPONENT: &'static str = "Trans";
+    pub const TRANS_I18N_KEY: &'static str = "i18nKey";
+    pub const TRANS_COMPONENTS: &'static str = "components";
+    pub const TRANS_VALUES: &'static str = "values";
+    pub const TRANS_DEFAULTS: &'static str = "defaults";
+    pub const RICH_TEXT_TRANS: &'static str = "trans";
+    pub const RICH_TEXT_INDEXED: &'static str = "indexed";
+}
+
+/// JSX 속성 번역 정책
+pub struct JsxAttributes;
+
+impl JsxAttributes {
+    /// 항상 번역 대상인 속성 (사용자에게 보이는 텍스트)
+    pub const TRANSLATABLE: &'static [&'static str] = &["alt", "title", "placeholder"];
+    pub const TRANSLATABLE_PREFIX: &'static str = "aria-";
+    /// 절대 건드리지 않는 속성 (식별자, 경로, 스타일)
+    pub const NON_TRANSLATABLE: &'static [&'static str] = &["className", "id", "key", "href", "style"];
+    pub const NON_TRANSLATABLE_PREFIX: &'static str = "data-";
+}
+
+/// 리치 텍스트로 묶을 수 있는 인라인 HTML 엘리먼트
+/// 대문자로 시작하는 컴포넌트(<Link> 등)는 자식이 단순하면 항상 허용
+pub struct InlineElements;
+
+impl InlineElements {
+    pub const TAGS: &'static [&'static str] = &[
+        "a", "abbr", "b", "br", "code", "em", "i", "kbd", "mark", "q", "s", "small", "span",
+        "strong", "sub", "sup", "u",
+    ];
 }
 
 /// 정규식 패턴
@@ -80,19 +294,32 @@ impl RegexPatterns {
         &REACT_HOOK
     }
 
+    /// 한글 전체 블록: 자모, 호환 자모, 자모 확장-A/B, 완성형 음절, 반각 자모
+    /// "ㅋㅋ", "ㄱ" 같은 자모만 있는 문자열도 감지
     pub fn korean_text() -> &'static Regex {
         static KOREAN_TEXT: LazyLock<Regex> = LazyLock::new(|| {
-            Regex::new(r"[가-힣]").unwrap()
+            Regex::new(
+                r"[\u{1100}-\u{11FF}\u{3130}-\u{318F}\u{A960}-\u{A97F}\u{AC00}-\u{D7AF}\u{D7B0}-\u{D7FF}\u{FFA0}-\u{FFDC}]",
+            )
+            .unwrap()
         });
         &KOREAN_TEXT
     }
 
     pub fn server_component() -> &'static Regex {
         static SERVER_COMPONENT: LazyLock<Regex> = LazyLock::new(|| {
-            // await getServerTranslation() 패턴 확인
-            Regex::new(r"await\s+getServerTranslation\s*\(\)").unwrap()
+            // await getServerTranslation() / await getTranslations() 패턴 확인
+            Regex::new(r"await\s+(getServerTranslation|getTranslations)\s*\(").unwrap()
         });
         &SERVER_COMPONENT
     }
+
+    /// 이벤트 핸들러 prop (onClick, onChange 등)
+    pub fn event_handler_prop() -> &'static Regex {
+        static EVENT_HANDLER_PROP: LazyLock<Regex> = LazyLock::new(|| {
+            Regex::new(r"^on[A-Z]").unwrap()
+        });
+        &EVENT_HANDLER_PROP
+    }
 }
 
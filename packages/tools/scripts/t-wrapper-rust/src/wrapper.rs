@@ -1,29 +1,148 @@
-use anyhow::Result;
+use anyhow::{bail, Result};
 use glob::glob;
 use std::fs;
 use std::time::Instant;
-use swc_ecma_ast::*;
 use rayon::prelude::*;
+use swc_ecma_ast::{Expr, Module};
 use crate::common::ScriptConfig;
-use crate::ast::ast_transformers::transform_module;
+use crate::ast::ast_helpers::{source_text_regex, SkippedString, TranslationWarning};
+use crate::ast::key_generator::KeyStrategy;
+use crate::ast::component_classifier::{classify_module, contains_react_component, Classification};
+use crate::ast::ast_transformers::{transform_module_with_config, OutsideComponent, TransformerConfig};
 use crate::applier::translation_applier::{apply_translations_to_ast, write_ast_to_file, ApplierConfig};
-use crate::parser::{parse_file, ParseOptions};
+use crate::manager::import_manager::{
+    ensure_default_import, ensure_named_import, i18n_instance_callee, translation_callee,
+    translation_callees,
+};
+use crate::parser::{parse_file_with_comments, ParseOptions};
+use crate::utils::constants::{NonComponentStrategies, StringConstants};
 
 #[derive(Debug)]
 pub struct WrapResult {
     pub processed_files: Vec<String>,
+    /// mode가 없을 때 파일마다 고른 client/server와 그 근거
+    pub classifications: Vec<FileClassification>,
+    /// 변환하지 않고 보고한 문자열 (컴포넌트 밖, 낮은 신뢰도 등)
+    pub skipped: Vec<FileSkippedStrings>,
+    /// 변환했지만 검토가 필요한 문자열 (옮길 수 없는 포맷 등)
+    pub warnings: Vec<FileWarnings>,
     pub total_time_ms: u128,
 }
 
+#[derive(Debug, Clone)]
+pub struct FileClassification {
+    pub file: String,
+    pub classification: Classification,
+}
+
+#[derive(Debug, Clone)]
+pub struct FileSkippedStrings {
+    pub file: String,
+    pub skipped: Vec<SkippedString>,
+}
+
+#[derive(Debug, Clone)]
+pub struct FileWarnings {
+    pub file: String,
+    pub warnings: Vec<TranslationWarning>,
+}
+
+/// 컴포넌트/커스텀 훅이 없는 파일(utils, API 클라이언트, 상수 파일)의 문자열 처리 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NonComponentStrategy {
+    /// 변환하지 않고 SkipReason::NoComponentScope로 보고 (기본값)
+    #[default]
+    Skip,
+    /// 전역 i18n 인스턴스로 변환: import i18n from "@/lib/i18n"; i18n.t("...")
+    Global,
+    /// 지연 번역 마커로 감쌈: import { tKey } from "<lazy_translation_source>"; tKey("...")
+    Lazy,
+}
+
+impl NonComponentStrategy {
+    /// ScriptConfig의 non_component_strategy 값 파싱 (None이면 Skip)
+    pub fn parse(value: Option<&str>) -> Result<Self> {
+        match value {
+            None | Some(NonComponentStrategies::SKIP) => Ok(Self::Skip),
+            Some(NonComponentStrategies::GLOBAL) => Ok(Self::Global),
+            Some(NonComponentStrategies::LAZY) => Ok(Self::Lazy),
+            Some(other) => bail!(
+                "Unknown non-component strategy: {} (expected one of skip, global, lazy)",
+                other
+            ),
+        }
+    }
+}
+
+/// 지연 번역 마커 함수와 그 함수를 import할 모듈
+struct LazyMarker<'a> {
+    source: &'a str,
+    function: &'a str,
+}
+
+impl LazyMarker<'_> {
+    /// 마커 호출의 callee (이미 별칭/네임스페이스로 import했으면 그 이름)
+    fn callee(&self, ast: &Module) -> Expr {
+        translation_callee(ast, self.source, self.function)
+    }
+
+    fn ensure_import(&self, ast: &mut Module) {
+        ensure_named_import(ast, self.source, self.function);
+    }
+}
+
+/// 파일 하나의 처리 결과
+struct FileOutcome {
+    file: String,
+    written: bool,
+    classification: Option<Classification>,
+    skipped: Vec<SkippedString>,
+    warnings: Vec<TranslationWarning>,
+}
+
 pub fn wrap_translations(config: Option<ScriptConfig>) -> Result<WrapResult> {
     let config = config.unwrap_or_default();
     let start_time = Instant::now();
+    let transformer_config = TransformerConfig {
+        rich_text: config.rich_text.clone(),
+        source_text: source_text_regex(
+            config.source_scripts.as_deref(),
+            config.source_text_pattern.as_deref(),
+        )?,
+        natural_language_threshold: config.natural_language_threshold,
+        key_strategy: KeyStrategy::parse(config.key_strategy.as_deref())?,
+        key_prefix: config.key_prefix,
+        counter_suffixes: config.counter_suffixes.clone(),
+        counter_names: config.counter_names.clone(),
+        josa_hints: config.josa_hints,
+        outside_component: OutsideComponent::Skip,
+        deferred_constants: None,
+        translation_callees: None,
+    };
+    let non_component_strategy = NonComponentStrategy::parse(config.non_component_strategy.as_deref())?;
+    let i18n_instance_source = config
+        .i18n_instance_source
+        .as_deref()
+        .unwrap_or(NonComponentStrategies::DEFAULT_I18N_INSTANCE_SOURCE);
+    let lazy_marker = config.lazy_translation_source.as_deref().map(|source| LazyMarker {
+        source,
+        function: config
+            .lazy_translation_function
+            .as_deref()
+            .unwrap_or(NonComponentStrategies::LAZY_TRANSLATION_FUNCTION),
+    });
+    if non_component_strategy == NonComponentStrategy::Lazy && lazy_marker.is_none() {
+        bail!("Non-component strategy \"lazy\" requires lazy_translation_source (the module that exports the marker function)");
+    }
+    if config.defer_constants && lazy_marker.is_none() {
+        bail!("defer_constants requires lazy_translation_source (the module that exports the marker function)");
+    }
     
     let file_paths: Vec<_> = glob(&config.source_pattern)?
         .filter_map(|entry| entry.ok())
         .collect();
     
-    let processed_files: Vec<String> = file_paths
+    let outcomes: Vec<FileOutcome> = file_paths
         .par_iter()
         .filter_map(|file_path| {
             let code = match fs::read_to_string(file_path) {
@@ -31,42 +150,175 @@ pub fn wrap_translations(config: Option<ScriptConfig>) -> Result<WrapResult> {
                 Err(_) => return None,
             };
             
-            let mut ast = match parse_file(&code, ParseOptions::default()) {
-                Ok(module) => module,
+            let (mut ast, comments) = match parse_file_with_comments(&code, ParseOptions::default()) {
+                Ok(parsed) => parsed,
                 Err(e) => {
                     eprintln!("❌ Error parsing {}: {}", file_path.display(), e);
                     return None;
                 }
             };
             
-            let (transform_result, modified_functions) = transform_module(&mut ast, code.clone());
+            // 컴포넌트가 있는 파일은 컴포넌트 밖 문자열을 보고만 하고,
+            // 컴포넌트가 없는 파일은 non_component_strategy를 따름
+            let has_component = contains_react_component(&ast);
+            let outside_component = match (has_component, non_component_strategy) {
+                (true, _) | (false, NonComponentStrategy::Skip) => OutsideComponent::Skip,
+                (false, NonComponentStrategy::Global) => {
+                    OutsideComponent::Callee(Box::new(i18n_instance_callee(&ast, i18n_instance_source)))
+                }
+                (false, NonComponentStrategy::Lazy) => lazy_marker
+                    .as_ref()
+                    .map_or(OutsideComponent::Skip, |marker| {
+                        OutsideComponent::Callee(Box::new(marker.callee(&ast)))
+                    }),
+            };
+            // 컴포넌트가 있는 파일의 상수 객체/배열은 마커로 감싸고 사용처에서 t()를 부름
+            let deferred_constants = lazy_marker
+                .as_ref()
+                .filter(|_| config.defer_constants && has_component)
+                .map(|marker| Box::new(marker.callee(&ast)));
+            let file_transformer_config = TransformerConfig {
+                outside_component,
+                deferred_constants,
+                translation_callees: Some(translation_callees(
+                    &ast,
+                    &config.translation_import_source,
+                    config
+                        .server_translation_function
+                        .as_deref()
+                        .unwrap_or(StringConstants::GET_SERVER_TRANSLATION),
+                )),
+                ..transformer_config.clone()
+            };
+
+            let (transform_result, modified_functions) = transform_module_with_config(
+                &mut ast,
+                code.clone(),
+                &file_transformer_config,
+                Some(&comments),
+            );
+            let file_path_str = file_path.to_string_lossy().to_string();
             
             if transform_result.was_modified {
+                let classification = (config.mode.is_none() && has_component).then(|| {
+                    classify_module(
+                        &ast,
+                        &file_path_str,
+                        config
+                            .server_translation_function
+                            .as_deref()
+                            .unwrap_or(StringConstants::GET_SERVER_TRANSLATION),
+                    )
+                });
                 let applier_config = ApplierConfig {
-                    mode: config.mode.clone(),
+                    mode: config.mode.clone().or_else(|| {
+                        classification
+                            .as_ref()
+                            .map(|classification| classification.mode.as_str().to_string())
+                    }),
                     framework: config.framework.clone(),
                     server_translation_function: config.server_translation_function.clone(),
                     translation_import_source: config.translation_import_source.clone(),
+                    key_prefix: config.key_prefix,
                 };
                 
-                apply_translations_to_ast(&mut ast, &modified_functions, &applier_config);
+                apply_translations_to_ast(
+                    &mut ast,
+                    &modified_functions,
+                    &transform_result.translation_bindings,
+                    &applier_config,
+                );
+
+                if !has_component {
+                    match non_component_strategy {
+                        NonComponentStrategy::Global => {
+                            ensure_default_import(
+                                &mut ast,
+                                i18n_instance_source,
+                                NonComponentStrategies::I18N_INSTANCE,
+                            );
+                        }
+                        NonComponentStrategy::Lazy => {
+                            if let Some(marker) = &lazy_marker {
+                                marker.ensure_import(&mut ast);
+                            }
+                        }
+                        NonComponentStrategy::Skip => {}
+                    }
+                }
+
+                if !transform_result.deferred_constants.is_empty() {
+                    if let Some(marker) = &lazy_marker {
+                        marker.ensure_import(&mut ast);
+                    }
+                }
+
+                if transform_result.uses_trans_component {
+                    ensure_named_import(
+                        &mut ast,
+                        &config.translation_import_source,
+                        StringConstants::TRANS_COMPONENT,
+                    );
+                }
                 
-                if let Err(e) = write_ast_to_file(&ast, &file_path.to_string_lossy()) {
+                if let Err(e) = write_ast_to_file(&ast, &file_path_str, Some(&comments)) {
                     eprintln!("❌ Error writing {}: {}", file_path.display(), e);
                     return None;
                 }
                 
-                Some(file_path.to_string_lossy().to_string())
+                Some(FileOutcome {
+                    file: file_path_str,
+                    written: true,
+                    classification,
+                    skipped: transform_result.skipped,
+                    warnings: transform_result.warnings,
+                })
             } else {
-                None
+                Some(FileOutcome {
+                    file: file_path_str,
+                    written: false,
+                    classification: None,
+                    skipped: transform_result.skipped,
+                    warnings: transform_result.warnings,
+                })
             }
         })
         .collect();
     
+    let mut processed_files = Vec::new();
+    let mut classifications = Vec::new();
+    let mut skipped = Vec::new();
+    let mut warnings = Vec::new();
+    for outcome in outcomes {
+        if outcome.written {
+            processed_files.push(outcome.file.clone());
+        }
+        if let Some(classification) = outcome.classification {
+            classifications.push(FileClassification {
+                file: outcome.file.clone(),
+                classification,
+            });
+        }
+        if !outcome.warnings.is_empty() {
+            warnings.push(FileWarnings {
+                file: outcome.file.clone(),
+                warnings: outcome.warnings,
+            });
+        }
+        if !outcome.skipped.is_empty() {
+            skipped.push(FileSkippedStrings {
+                file: outcome.file,
+                skipped: outcome.skipped,
+            });
+        }
+    }
     let total_time_ms = start_time.elapsed().as_millis();
     
     Ok(WrapResult {
         processed_files,
+        classifications,
+        skipped,
+        warnings,
         total_time_ms,
     })
 }
@@ -1,20 +1,76 @@
-use t_wrapper_rust::{has_ignore_comment, should_skip_path, is_react_component};
+use swc_common::Spanned;
+use t_wrapper_rust::parser::{parse_file_with_comments, ParseOptions};
+use t_wrapper_rust::{
+    clean_jsx_text, has_ignore_comment, is_non_translatable_attribute, is_react_component,
+    is_translatable_attribute, natural_language_score, should_skip_path, source_text_regex,
+    split_jsx_whitespace, IgnoreDirective, IgnoreDirectives, NodePath, RegexPatterns, SkipReason,
+    TextContext,
+};
+
+/// 코드를 파싱해서 최상위 구문들의 span과 i18n-ignore 지시어를 반환
+fn parse_statements(code: &str) -> (Vec<swc_common::Span>, IgnoreDirectives) {
+    let (module, comments) = parse_file_with_comments(code, ParseOptions::default()).unwrap();
+    let spans = module.body.iter().map(|item| item.span()).collect();
+    (spans, IgnoreDirectives::from_comments(&comments, code))
+}
 
 #[test]
 fn has_ignore_comment_leading_comments에_i18n_ignore가_있으면_true를_반환해야_함() {
     let code = r#"// i18n-ignore
 const text = "hello";"#;
+    let (spans, directives) = parse_statements(code);
+    let path = NodePath { span: spans[0], statements: &[], directives: &directives };
     
-    assert!(has_ignore_comment((), Some(code)));
+    assert!(has_ignore_comment(&path));
+}
+
+#[test]
+fn has_ignore_comment_다른_구문의_주석은_적용하지_않아야_함() {
+    let code = r#"// i18n-ignore
+const a = "hello";
+const b = "world";"#;
+    let (spans, directives) = parse_statements(code);
+    let path = NodePath { span: spans[1], statements: &[], directives: &directives };
+
+    assert!(!has_ignore_comment(&path));
+}
+
+#[test]
+fn has_ignore_comment_부모_구문의_주석도_확인해야_함() {
+    let code = r#"// i18n-ignore
+function label() {
+  return "hello";
+}"#;
+    let (spans, directives) = parse_statements(code);
+    let lo = swc_common::BytePos(code.find("\"hello\"").unwrap() as u32 + 1);
+    let inner = swc_common::Span::new(lo, lo + swc_common::BytePos(7));
+    let path = NodePath { span: inner, statements: &spans, directives: &directives };
+
+    assert!(has_ignore_comment(&path));
+}
+
+#[test]
+fn ignore_directive_주석_본문에서_지시어를_파싱해야_함() {
+    assert_eq!(IgnoreDirective::parse(" i18n-ignore"), Some(IgnoreDirective::Node));
+    assert_eq!(IgnoreDirective::parse(" i18n-ignore 외부 API 값 "), Some(IgnoreDirective::Node));
+    assert_eq!(IgnoreDirective::parse("* i18n-ignore-next-line "), Some(IgnoreDirective::NextLine));
+    assert_eq!(IgnoreDirective::parse("*\n * i18n-ignore\n "), Some(IgnoreDirective::Node));
+    assert_eq!(IgnoreDirective::parse(" i18n-ignore-start"), Some(IgnoreDirective::Start));
+    assert_eq!(IgnoreDirective::parse(" i18n-ignore-end"), Some(IgnoreDirective::End));
+    assert_eq!(IgnoreDirective::parse(" i18n-ignore-file"), Some(IgnoreDirective::File));
+    assert_eq!(IgnoreDirective::parse(" i18n-ignored"), None);
+    assert_eq!(IgnoreDirective::parse(" TODO: i18n-ignore"), None);
 }
 
 #[test]
 fn should_skip_path_i18n_ignore_주석이_있으면_true를_반환해야_함() {
     let code = r#"// i18n-ignore
 const text = "hello";"#;
+    let (spans, directives) = parse_statements(code);
+    let path = NodePath { span: spans[0], statements: &[], directives: &directives };
     
-    let should_skip = should_skip_path((), has_ignore_comment, Some(code));
-    assert!(should_skip);
+    let should_skip = should_skip_path(&path, &[], has_ignore_comment);
+    assert_eq!(should_skip, Some(SkipReason::IgnoreComment));
 }
 
 #[test]
@@ -28,3 +84,115 @@ fn is_react_component_use로_시작하는_훅은_컴포넌트로_인식해야_
     assert!(is_react_component("useState"));
     assert!(is_react_component("useTranslation"));
 }
+
+#[test]
+fn clean_jsx_text_줄바꿈과_들여쓰기는_공백_하나로_합쳐야_함() {
+    assert_eq!(clean_jsx_text("\n    안녕하세요\n    반갑습니다\n  "), "안녕하세요 반갑습니다");
+    assert_eq!(clean_jsx_text("\n    \n  "), "");
+}
+
+#[test]
+fn clean_jsx_text_같은_줄의_앞뒤_공백은_유지해야_함() {
+    assert_eq!(clean_jsx_text(" 안녕 "), " 안녕 ");
+    assert_eq!(clean_jsx_text(" 안녕\n  "), " 안녕");
+    assert_eq!(split_jsx_whitespace(" 안녕 하세요  "), (" ", "안녕 하세요", "  "));
+}
+
+#[test]
+fn jsx_attribute_policy_번역_속성과_제외_속성을_구분해야_함() {
+    assert!(is_translatable_attribute("placeholder"));
+    assert!(is_translatable_attribute("aria-label"));
+    assert!(!is_translatable_attribute("className"));
+
+    assert!(is_non_translatable_attribute("className"));
+    assert!(is_non_translatable_attribute("data-testid"));
+    assert!(is_non_translatable_attribute("xlink:href"));
+    assert!(!is_non_translatable_attribute("alt"));
+}
+
+#[test]
+fn should_skip_path_부모_문맥이_있으면_가장_안쪽_이유를_반환해야_함() {
+    let code = "const text = \"hello\";";
+    let (spans, directives) = parse_statements(code);
+    let path = NodePath { span: spans[0], statements: &[], directives: &directives };
+
+    assert_eq!(should_skip_path(&path, &[], has_ignore_comment), None);
+    assert_eq!(
+        should_skip_path(
+            &path,
+            &[SkipReason::TranslationCall, SkipReason::PropertyKey],
+            has_ignore_comment,
+        ),
+        Some(SkipReason::PropertyKey)
+    );
+}
+
+#[test]
+fn korean_text_한글_자모와_호환_자모도_감지해야_함() {
+    let korean = RegexPatterns::korean_text();
+    assert!(korean.is_match("안녕"));
+    assert!(korean.is_match("ㅋㅋ"));
+    assert!(korean.is_match("ㄱ"));
+    assert!(korean.is_match("\u{1100}\u{1161}"));
+    assert!(!korean.is_match("hello 漢字 かな"));
+}
+
+#[test]
+fn source_text_regex_스크립트_목록과_커스텀_정규식으로_감지해야_함() {
+    assert!(source_text_regex(None, None).unwrap().is_none());
+
+    let japanese = vec!["Hiragana".to_string(), "Katakana".to_string(), "Han".to_string()];
+    let regex = source_text_regex(Some(&japanese), None).unwrap().unwrap();
+    assert!(regex.is_match("こんにちは"));
+    assert!(regex.is_match("カタカナ"));
+    assert!(regex.is_match("漢字"));
+    assert!(!regex.is_match("안녕"));
+
+    let custom = source_text_regex(Some(&japanese), Some(r"[а-я]")).unwrap().unwrap();
+    assert!(custom.is_match("привет"));
+    assert!(!custom.is_match("こんにちは"));
+}
+
+#[test]
+fn source_text_regex_잘못된_설정은_에러를_반환해야_함() {
+    assert!(source_text_regex(Some(&["Klingon".to_string()]), None).is_err());
+    assert!(source_text_regex(Some(&["Han}|.".to_string()]), None).is_err());
+    assert!(source_text_regex(Some(&[]), None).is_err());
+    assert!(source_text_regex(None, Some("[")).is_err());
+}
+
+#[test]
+fn natural_language_score_문장형_텍스트는_높은_점수를_받아야_함() {
+    let threshold = 0.5;
+    assert!(natural_language_score("Are you sure you want to delete this?", TextContext::Code) >= threshold);
+    assert!(natural_language_score("Save changes", TextContext::Code) >= threshold);
+    assert!(natural_language_score("Loading...", TextContext::JsxText) >= threshold);
+    assert!(natural_language_score("Save", TextContext::JsxText) >= threshold);
+    assert!(natural_language_score("Save", TextContext::Code) < threshold);
+}
+
+#[test]
+fn natural_language_score_코드처럼_보이는_문자열은_0점이어야_함() {
+    for text in [
+        "onClick",
+        "user_id",
+        "SOME_KEY",
+        "btn-primary",
+        "https://example.com/docs",
+        "/api/users",
+        "application/json",
+        "flex items-center px-4",
+        "color: red; margin: 0",
+        "404",
+        "",
+    ] {
+        assert_eq!(natural_language_score(text, TextContext::JsxText), 0.0, "{}", text);
+    }
+}
+
+#[test]
+fn natural_language_score_문맥이_점수를_올려야_함() {
+    let code = natural_language_score("Search", TextContext::Code);
+    assert!(natural_language_score("Search", TextContext::TranslatableAttribute) > code);
+    assert!(natural_language_score("Search", TextContext::JsxText) > code);
+}
@@ -1,4 +1,15 @@
-use t_wrapper_rust::ast_transformers::{transform_function_body, TransformResult};
+#![allow(non_snake_case)]
+
+use t_wrapper_rust::ast_transformers::{
+    transform_function_body, transform_module, transform_module_with_config, TransformResult,
+    OutsideComponent, TransformerConfig,
+};
+use t_wrapper_rust::parser::{
+    generate_code, generate_code_with_comments, parse_file, parse_file_with_comments, ParseOptions,
+};
+use t_wrapper_rust::{
+    hash_key, source_text_regex, translation_callee, KeyStrategy, SkipReason, WarningKind,
+};
 
 #[test]
 fn transform_function_body_한국어_문자열_리터럴을_t_호출로_변환해야_함() {
@@ -51,3 +62,1074 @@ fn transform_function_body_i18n_ignore_주석이_있으면_변환하지_않아
     let result = transform_function_body((), code);
     assert!(!result.was_modified);
 }
+
+fn transform_code(code: &str) -> String {
+    let mut module = parse_file(code, ParseOptions::default()).unwrap();
+    transform_module(&mut module, code.to_string());
+    generate_code(&module).unwrap()
+}
+
+#[test]
+fn transform_module_표현식_없는_템플릿_리터럴을_t_호출로_교체해야_함() {
+    let output = transform_code(r#"const text = `안녕하세요`;"#);
+
+    assert!(output.contains(r#"t("안녕하세요")"#), "{}", output);
+    assert!(!output.contains('`'), "{}", output);
+}
+
+#[test]
+fn transform_module_템플릿_리터럴을_i18next_interpolation으로_교체해야_함() {
+    let output = transform_code(r#"const text = `안녕 ${name}님, ${user.age}살`;"#);
+
+    assert!(
+        output.contains(r#"t("안녕 {{name}}님, {{user_age}}살", {"#),
+        "{}",
+        output
+    );
+    assert!(output.contains("user_age: user.age"), "{}", output);
+    assert!(!output.contains('`'), "{}", output);
+}
+
+#[test]
+fn transform_module_한국어가_없는_템플릿과_태그드_템플릿은_유지해야_함() {
+    let output = transform_code(
+        r#"const a = `hello ${name}`;
+const b = styled.div`color: red; content: "안녕";`;"#,
+    );
+
+    assert!(output.contains("`hello ${name}`"), "{}", output);
+    assert!(output.contains("styled.div`"), "{}", output);
+    assert!(!output.contains("t("), "{}", output);
+}
+
+#[test]
+fn transform_module_JSXText를_t_호출_ExpressionContainer로_감싸야_함() {
+    let output = transform_code(r#"const el = <div>안녕하세요</div>;"#);
+
+    assert!(output.contains(r#"<div>{t("안녕하세요")}</div>"#), "{}", output);
+}
+
+#[test]
+fn transform_module_JSXText_공백은_React_규칙을_따라야_함() {
+    let output = transform_code(
+        r#"const el = (
+  <p>
+    안녕하세요
+    반갑습니다
+  </p>
+);
+const inline = <span><b>이름</b> 님 환영합니다 <i>!</i></span>;"#,
+    );
+
+    assert!(output.contains(r#"<p>{t("안녕하세요 반갑습니다")}</p>"#), "{}", output);
+    assert!(output.contains(r#"{" "}{t("님 환영합니다")}{" "}"#), "{}", output);
+    assert!(output.contains(r#"<b>{t("이름")}</b>"#), "{}", output);
+}
+
+#[test]
+fn transform_module_JSX_속성의_한국어_문자열은_ExpressionContainer로_감싸야_함() {
+    let output = transform_code(
+        r#"const el = <input placeholder="검색" aria-label="검색창" title={`${name}님`} />;"#,
+    );
+
+    assert!(output.contains(r#"placeholder={t("검색")}"#), "{}", output);
+    assert!(output.contains(r#"aria-label={t("검색창")}"#), "{}", output);
+    assert!(output.contains(r#"title={t("{{name}}님", {"#), "{}", output);
+}
+
+#[test]
+fn transform_module_제외_속성의_한국어는_변환하지_않아야_함() {
+    let output = transform_code(
+        r#"const el = <div className="제목" data-label="라벨" id="아이디" style={{ content: "내용" }} />;"#,
+    );
+
+    assert!(!output.contains("t("), "{}", output);
+}
+
+/// with_comments면 주석을 함께 파싱해 i18n-ignore 판단과 출력에 사용
+fn transform_with_config(
+    code: &str,
+    config: &TransformerConfig,
+    with_comments: bool,
+) -> (String, TransformResult) {
+    let (mut module, comments) = parse_file_with_comments(code, ParseOptions::default()).unwrap();
+    let comments = with_comments.then_some(&comments);
+    let (result, _) = transform_module_with_config(&mut module, code.to_string(), config, comments);
+    (generate_code_with_comments(&module, comments).unwrap(), result)
+}
+
+fn rich_text_config(rich_text: &str) -> TransformerConfig {
+    TransformerConfig {
+        rich_text: Some(rich_text.to_string()),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn transform_module_리치_텍스트를_Trans_컴포넌트로_변환해야_함() {
+    let (output, result) = transform_with_config(
+        r#"const el = <p>{name}님, 약관에 <a href="/terms" title="약관">동의</a>합니다</p>;"#,
+        &rich_text_config("trans"),
+        false,
+    );
+    assert!(result.was_modified);
+
+    assert!(
+        output.contains(r#"i18nKey="{{name}}님, 약관에 <0>동의</0>합니다""#),
+        "{}",
+        output
+    );
+    assert!(output.contains("components={["), "{}", output);
+    assert!(output.contains(r#"<a href="/terms" title={t("약관")}/>"#), "{}", output);
+    assert!(output.contains("values={{"), "{}", output);
+}
+
+#[test]
+fn transform_module_리치_텍스트를_인덱스_태그_t_호출로_변환해야_함() {
+    let (output, result) = transform_with_config(
+        r#"const el = <p>약관에 <b>동의</b>합니다</p>;"#,
+        &rich_text_config("indexed"),
+        false,
+    );
+    assert!(result.was_modified);
+
+    assert!(output.contains(r#"{t("약관에 <0>동의</0>합니다", {"#), "{}", output);
+    assert!(output.contains("components: ["), "{}", output);
+    assert!(output.contains("<b/>"), "{}", output);
+    assert!(!output.contains("Trans"), "{}", output);
+}
+
+#[test]
+fn transform_module_블록_엘리먼트만_있으면_리치_텍스트로_묶지_않아야_함() {
+    let (output, result) = transform_with_config(
+        r#"const el = <div>
+  <span>안녕하세요</span>
+  <div>반갑습니다</div>
+</div>;"#,
+        &rich_text_config("trans"),
+        false,
+    );
+    assert!(result.was_modified);
+
+    assert!(!output.contains("Trans"), "{}", output);
+    assert!(output.contains(r#"<span>{t("안녕하세요")}</span>"#), "{}", output);
+}
+
+#[test]
+fn transform_module_인접한_JSX_텍스트와_표현식을_하나의_t_호출로_합쳐야_함() {
+    let output = transform_code(
+        r#"const a = <span>{count}개의 항목이 있습니다</span>;
+const b = <p>안녕하세요 {user.name}님</p>;"#,
+    );
+
+    assert!(
+        output.contains(r#"<span>{t("{{count}}개의 항목이 있습니다", {"#),
+        "{}",
+        output
+    );
+    assert!(output.contains(r#"<p>{t("안녕하세요 {{user_name}}님", {"#), "{}", output);
+    assert!(output.contains("user_name: user.name"), "{}", output);
+}
+
+#[test]
+fn transform_module_JSX_메시지는_템플릿_리터럴과_같은_결과를_만들어야_함() {
+    let jsx = transform_code(r#"const a = <p>안녕하세요 {name}님</p>;"#);
+    let tpl = transform_code(r#"const a = <p>{`안녕하세요 ${name}님`}</p>;"#);
+
+    assert_eq!(jsx, tpl);
+}
+
+#[test]
+fn transform_module_조건부_렌더링은_메시지를_끊어야_함() {
+    let output = transform_code(r#"const a = <p>{isNew && <Badge />}새 알림 {count}</p>;"#);
+
+    assert!(output.contains("{isNew && <Badge/>}"), "{}", output);
+    assert!(output.contains(r#"{t("새 알림 {{count}}", {"#), "{}", output);
+}
+
+#[test]
+fn transform_module_복잡한_표현식은_서로_다른_변수명을_가져야_함() {
+    let output = transform_code(r#"const text = `합계 ${a + 1}, 평균 ${b / 2}`;"#);
+
+    assert!(output.contains(r#"t("합계 {{expr0}}, 평균 {{expr1}}", {"#), "{}", output);
+    assert!(output.contains("expr0: a + 1"), "{}", output);
+    assert!(output.contains("expr1: b / 2"), "{}", output);
+}
+
+#[test]
+fn transform_module_호출과_옵셔널_체이닝에서_의미있는_변수명을_만들어야_함() {
+    let output = transform_code(
+        r#"const text = `${formatDate(d)} ${user?.profile.name}님 ${items.length}개 ${list[0]}`;"#,
+    );
+
+    assert!(
+        output.contains(
+            r#"t("{{formatDate}} {{user_profile_name}}님 {{count}}개 {{list_0}}", {"#
+        ),
+        "{}",
+        output
+    );
+    assert!(output.contains("count: items.length"), "{}", output);
+}
+
+#[test]
+fn transform_module_같은_표현식은_변수명을_재사용하고_다른_표현식은_충돌하지_않아야_함() {
+    let output = transform_code(r#"const text = `${name}님 ${name}님 ${user.name} ${user_name}`;"#);
+
+    assert!(
+        output.contains(r#"t("{{name}}님 {{name}}님 {{user_name}} {{user_name2}}", {"#),
+        "{}",
+        output
+    );
+    assert_eq!(output.matches("    name,").count(), 1, "{}", output);
+    assert!(output.contains("user_name: user.name"), "{}", output);
+    assert!(output.contains("user_name2: user_name"), "{}", output);
+}
+
+#[test]
+fn transform_module_문자열_연결을_하나의_interpolation_t_호출로_합쳐야_함() {
+    let output = transform_code(
+        r#"const a = "총 " + count + "건";
+const b = "안녕하세요, " + user.name + "님";"#,
+    );
+
+    assert!(output.contains(r#"t("총 {{count}}건", {"#), "{}", output);
+    assert!(output.contains(r#"t("안녕하세요, {{user_name}}님", {"#), "{}", output);
+    assert!(!output.contains(r#"t("총 ")"#), "{}", output);
+}
+
+#[test]
+fn transform_module_문자열_앞의_숫자_덧셈은_하나의_값으로_유지해야_함() {
+    let output = transform_code(r#"const a = price + tax + "원";"#);
+
+    assert!(output.contains(r#"t("{{expr0, currency(KRW)}}", {"#), "{}", output);
+    assert!(output.contains("expr0: price + tax"), "{}", output);
+}
+
+#[test]
+fn transform_module_한국어가_없는_문자열_연결은_유지해야_함() {
+    let output = transform_code(r#"const a = "/users/" + id + "/edit";"#);
+
+    assert!(!output.contains("t("), "{}", output);
+}
+
+#[test]
+fn transform_module_이미_t로_래핑된_문자열은_다시_감싸지_않아야_함() {
+    let (output, result) = transform_with_config(
+        r#"const a = t("안녕하세요");
+const b = i18n.t("반갑습니다");"#,
+        &TransformerConfig::default(),
+        false,
+    );
+
+    assert!(output.contains(r#"t("안녕하세요")"#), "{}", output);
+    assert!(!output.contains("t(t("), "{}", output);
+    assert!(!result.was_modified);
+    assert_eq!(result.skipped.len(), 2);
+    assert_eq!(result.skipped[0].reason, SkipReason::TranslationCall);
+    assert_eq!(result.skipped[1].line, Some(2));
+}
+
+#[test]
+fn transform_module_모듈_경로_프로퍼티_키_TS_문맥의_문자열은_스킵해야_함() {
+    let (output, result) = transform_with_config(
+        r#"'use client';
+import data from "./데이터";
+const lazy = import("./한국어");
+const labels = { ["대기"]: 1 };
+const value = labels["대기"];
+type Status = "대기" | "완료";
+enum Kind { Pending = "대기" }
+declare const VERSION = "버전";"#,
+        &TransformerConfig::default(),
+        false,
+    );
+
+    assert!(!output.contains(" t("), "{}", output);
+    assert!(!result.was_modified);
+
+    let module_sources: Vec<_> = result
+        .skipped
+        .iter()
+        .filter(|s| s.reason == SkipReason::ModuleSource)
+        .map(|s| (s.value.as_str(), s.line))
+        .collect();
+    assert_eq!(module_sources, vec![("./데이터", Some(2)), ("./한국어", Some(3))]);
+
+    let reasons: Vec<SkipReason> = result.skipped.iter().map(|s| s.reason).collect();
+    assert!(reasons.contains(&SkipReason::PropertyKey));
+    assert!(reasons.contains(&SkipReason::TsType));
+    assert!(reasons.contains(&SkipReason::EnumMember));
+    assert!(reasons.contains(&SkipReason::AmbientDeclaration));
+}
+
+#[test]
+fn transform_module_디렉티브_프롤로그는_스킵해야_함() {
+    let (output, result) = transform_with_config(
+        r#"'use client';
+"한국어 디렉티브";
+const a = "안녕";"#,
+        &TransformerConfig::default(),
+        false,
+    );
+
+    assert!(output.contains(r#""한국어 디렉티브";"#), "{}", output);
+    assert!(output.contains(r#"t("안녕")"#), "{}", output);
+    assert_eq!(result.skipped[0].reason, SkipReason::Directive);
+}
+
+#[test]
+fn transform_module_i18n_ignore는_주석이_붙은_구문에만_적용되어야_함() {
+    let (output, result) = transform_with_config(
+        r#"const a = "첫째";
+// i18n-ignore
+const b = "둘째";
+const c = /* i18n-ignore */ format("셋째");
+const d = "넷째";"#,
+        &TransformerConfig::default(),
+        true,
+    );
+
+    assert!(output.contains(r#"t("첫째")"#), "{}", output);
+    assert!(output.contains(r#"const b = "둘째";"#), "{}", output);
+    assert!(output.contains(r#"format("셋째")"#), "{}", output);
+    assert!(output.contains(r#"t("넷째")"#), "{}", output);
+    assert!(output.contains("// i18n-ignore"), "주석은 출력에 유지되어야 함: {}", output);
+
+    let skipped: Vec<(&str, SkipReason)> =
+        result.skipped.iter().map(|s| (s.value.as_str(), s.reason)).collect();
+    assert_eq!(
+        skipped,
+        vec![("둘째", SkipReason::IgnoreComment), ("셋째", SkipReason::IgnoreComment)]
+    );
+}
+
+#[test]
+fn transform_module_JSDoc_형식의_i18n_ignore도_인식해야_함() {
+    let (output, _) = transform_with_config(
+        r#"/** i18n-ignore */
+const a = "첫째";
+/**
+ * i18n-ignore 외부 API 값
+ */
+const b = "둘째";
+const c = "셋째";"#,
+        &TransformerConfig::default(),
+        true,
+    );
+
+    assert!(output.contains(r#"const a = "첫째";"#), "{}", output);
+    assert!(output.contains(r#"const b = "둘째";"#), "{}", output);
+    assert!(output.contains(r#"t("셋째")"#), "{}", output);
+}
+
+#[test]
+fn transform_module_함수_선언의_i18n_ignore는_본문_전체에_적용되어야_함() {
+    let (output, _) = transform_with_config(
+        r#"// i18n-ignore
+function Debug() {
+  return <div title="디버그">디버그 화면</div>;
+}
+function Page() {
+  return <div>페이지</div>;
+}"#,
+        &TransformerConfig::default(),
+        true,
+    );
+
+    assert!(output.contains("디버그 화면</div>"), "{}", output);
+    assert!(output.contains(r#"title="디버그""#), "{}", output);
+    assert!(output.contains(r#"{t("페이지")}"#), "{}", output);
+}
+
+#[test]
+fn transform_module_i18n_ignore_next_line은_다음_줄에만_적용되어야_함() {
+    let (output, _) = transform_with_config(
+        r#"function Page() {
+  // i18n-ignore-next-line
+  const a = "무시";
+  const b = "변환";
+  return (
+    <div>
+      {/* i18n-ignore-next-line */}
+      그대로
+      <p>번역</p>
+    </div>
+  );
+}"#,
+        &TransformerConfig::default(),
+        true,
+    );
+
+    assert!(output.contains(r#"const a = "무시";"#), "{}", output);
+    assert!(output.contains(r#"t("변환")"#), "{}", output);
+    assert!(!output.contains(r#"t("그대로")"#), "{}", output);
+    assert!(output.contains(r#"{t("번역")}"#), "{}", output);
+}
+
+#[test]
+fn transform_module_JSX의_i18n_ignore는_다음_자식에만_적용되어야_함() {
+    let (output, result) = transform_with_config(
+        r#"const el = (
+  <div>
+    {/* i18n-ignore */}
+    <span>브랜드명</span>
+    <p>설명</p>
+  </div>
+);"#,
+        &TransformerConfig::default(),
+        true,
+    );
+
+    assert!(output.contains("<span>브랜드명</span>"), "{}", output);
+    assert!(output.contains(r#"{t("설명")}"#), "{}", output);
+    assert_eq!(result.skipped[0].value, "브랜드명");
+}
+
+#[test]
+fn transform_module_i18n_ignore_start_end_범위는_스킵해야_함() {
+    let (output, _) = transform_with_config(
+        r#"const a = "앞";
+// i18n-ignore-start
+const b = "범위1";
+const c = "범위2";
+// i18n-ignore-end
+const d = "뒤";
+/* i18n-ignore-start */
+const e = "닫히지 않음";"#,
+        &TransformerConfig::default(),
+        true,
+    );
+
+    assert!(output.contains(r#"t("앞")"#), "{}", output);
+    assert!(output.contains(r#"const b = "범위1";"#), "{}", output);
+    assert!(output.contains(r#"const c = "범위2";"#), "{}", output);
+    assert!(output.contains(r#"t("뒤")"#), "{}", output);
+    assert!(output.contains(r#"const e = "닫히지 않음";"#), "{}", output);
+}
+
+#[test]
+fn transform_module_i18n_ignore_file은_파일_전체를_스킵해야_함() {
+    let (output, result) = transform_with_config(
+        r#"const a = "안녕";
+// i18n-ignore-file
+function Page() {
+  return <div>페이지</div>;
+}"#,
+        &TransformerConfig::default(),
+        true,
+    );
+
+    assert!(!output.contains("t("), "{}", output);
+    assert!(!result.was_modified);
+}
+
+#[test]
+fn transform_module_설정한_스크립트로_문자열_템플릿_JSX를_감지해야_함() {
+    let code = r#"function Page() {
+  const a = "こんにちは";
+  const b = `${name}さん`;
+  const c = "안녕";
+  return <div title="タイトル">日本語</div>;
+}"#;
+    let config = TransformerConfig {
+        source_text: source_text_regex(
+            Some(&["Hiragana".to_string(), "Katakana".to_string(), "Han".to_string()]),
+            None,
+        )
+        .unwrap(),
+        ..Default::default()
+    };
+    let (output, _) = transform_with_config(code, &config, false);
+
+    assert!(output.contains(r#"t("こんにちは")"#), "{}", output);
+    assert!(output.contains(r#"t("{{name}}さん""#), "{}", output);
+    assert!(output.contains(r#"const c = "안녕";"#), "{}", output);
+    assert!(output.contains(r#"title={t("タイトル")}"#), "{}", output);
+    assert!(output.contains(r#"{t("日本語")}"#), "{}", output);
+}
+
+#[test]
+fn transform_module_기본_설정은_한글_자모만_있는_문자열도_변환해야_함() {
+    let output = transform_code(r#"const a = "ㅋㅋ";"#);
+
+    assert!(output.contains(r#"t("ㅋㅋ")"#), "{}", output);
+}
+
+#[test]
+fn transform_module_자연어_감지기는_임계값_이상만_변환하고_근처는_보고해야_함() {
+    let code = r#"function Page() {
+  const message = "Are you sure you want to leave?";
+  const variant = "primary";
+  const label = "Save";
+  return (
+    <div className="flex items-center" title="Settings">
+      Welcome back
+      <Button type="submit">Save</Button>
+    </div>
+  );
+}"#;
+    let config = TransformerConfig {
+        natural_language_threshold: Some(0.5),
+        ..Default::default()
+    };
+    let (output, result) = transform_with_config(code, &config, false);
+
+    assert!(output.contains(r#"t("Are you sure you want to leave?")"#), "{}", output);
+    assert!(output.contains(r#"const variant = "primary";"#), "{}", output);
+    assert!(output.contains(r#"const label = "Save";"#), "{}", output);
+    assert!(output.contains(r#"className="flex items-center""#), "{}", output);
+    assert!(output.contains(r#"title={t("Settings")}"#), "{}", output);
+    assert!(output.contains(r#"{t("Welcome back")}"#), "{}", output);
+    assert!(output.contains(r#"{t("Save")}</Button>"#), "{}", output);
+    assert!(output.contains(r#"type="submit""#), "{}", output);
+
+    let low_confidence: Vec<&str> = result
+        .skipped
+        .iter()
+        .filter(|s| s.reason == SkipReason::LowConfidence)
+        .map(|s| s.value.as_str())
+        .collect();
+    assert_eq!(low_confidence, vec!["Save"]);
+}
+
+#[test]
+fn transform_module_자연어_감지기가_꺼져_있으면_영어를_변환하지_않아야_함() {
+    let output = transform_code(r#"const message = "Are you sure you want to leave?";"#);
+
+    assert!(!output.contains("t("), "{}", output);
+}
+
+#[test]
+fn transform_module_hash_키_전략은_원문을_defaultValue로_남겨야_함() {
+    let (output, _) = transform_with_config(
+        r#"const a = "안녕하세요";
+const b = `${name}님 환영합니다`;"#,
+        &TransformerConfig {
+            key_strategy: KeyStrategy::Hash,
+            ..Default::default()
+        },
+        false,
+    );
+
+    let hello = hash_key("안녕하세요");
+    let welcome = hash_key("{{name}}님 환영합니다");
+    assert!(output.contains(&format!(r#"t("{hello}", {{"#)), "{}", output);
+    assert!(output.contains(r#"defaultValue: "안녕하세요""#), "{}", output);
+    assert!(output.contains(&format!(r#"t("{welcome}", {{"#)), "{}", output);
+    assert!(output.contains(r#"defaultValue: "{{name}}님 환영합니다""#), "{}", output);
+    assert!(output.contains("name\n"), "interpolation 값도 유지해야 함: {}", output);
+}
+
+#[test]
+fn transform_module_slug_키_전략은_로마자_키를_만들어야_함() {
+    let (output, _) = transform_with_config(
+        r#"function Page() {
+  return <button>저장하기</button>;
+}"#,
+        &TransformerConfig {
+            key_strategy: KeyStrategy::Slug,
+            ..Default::default()
+        },
+        false,
+    );
+
+    assert!(output.contains(r#"t("jeojanghagi", {"#), "{}", output);
+    assert!(output.contains(r#"defaultValue: "저장하기""#), "{}", output);
+}
+
+#[test]
+fn transform_module_semantic_키_전략은_컴포넌트_엘리먼트_속성을_사용해야_함() {
+    let (output, _) = transform_with_config(
+        r#"const LoginForm = () => {
+  return (
+    <form>
+      <input placeholder="이메일" />
+      <button>로그인</button>
+      <p>
+        약관에 <a href="/terms">동의</a>합니다
+      </p>
+    </form>
+  );
+};"#,
+        &TransformerConfig {
+            key_strategy: KeyStrategy::Semantic,
+            rich_text: Some("trans".to_string()),
+            ..Default::default()
+        },
+        false,
+    );
+
+    assert!(output.contains(r#"t("LoginForm.input.placeholder", {"#), "{}", output);
+    assert!(output.contains(r#"t("LoginForm.button", {"#), "{}", output);
+    assert!(output.contains(r#"i18nKey="LoginForm.p""#), "{}", output);
+    assert!(output.contains(r#"defaults="약관에 <0>동의</0>합니다""#), "{}", output);
+}
+
+#[test]
+fn transform_module_기본_키_전략은_원문을_키로_사용해야_함() {
+    let output = transform_code(r#"const a = "안녕하세요";"#);
+
+    assert!(output.contains(r#"t("안녕하세요")"#), "{}", output);
+    assert!(!output.contains("defaultValue"), "{}", output);
+}
+
+#[test]
+fn transform_module_semantic_키는_변수명과_엘리먼트_구분자를_사용해야_함() {
+    let (output, _) = transform_with_config(
+        r#"function SettingsPage() {
+  const title = "설정";
+  const labels = { save: "저장" };
+  return (
+    <form>
+      <img src="/a.png" alt="상품 이미지" />
+      <button type="submit">로그인</button>
+    </form>
+  );
+}"#,
+        &TransformerConfig {
+            key_strategy: KeyStrategy::Semantic,
+            ..Default::default()
+        },
+        false,
+    );
+
+    assert!(output.contains(r#"t("SettingsPage.title", {"#), "{}", output);
+    assert!(output.contains(r#"t("SettingsPage.labels.save", {"#), "{}", output);
+    assert!(output.contains(r#"t("SettingsPage.img.alt", {"#), "{}", output);
+    assert!(output.contains(r#"t("SettingsPage.button.submit", {"#), "{}", output);
+}
+
+#[test]
+fn transform_module_semantic_키_충돌은_접미사로_해결해야_함() {
+    let (output, _) = transform_with_config(
+        r#"function Card() {
+  return (
+    <div>
+      <p>첫째</p>
+      <p>둘째</p>
+      <p>첫째</p>
+    </div>
+  );
+}"#,
+        &TransformerConfig {
+            key_strategy: KeyStrategy::Semantic,
+            ..Default::default()
+        },
+        false,
+    );
+
+    assert_eq!(output.matches(r#"t("Card.p", {"#).count(), 2, "{}", output);
+    assert!(output.contains(r#"t("Card.p_2", {"#), "{}", output);
+}
+
+#[test]
+fn transform_module_keyPrefix를_쓰면_컴포넌트_이름을_키에서_빼야_함() {
+    let (output, _) = transform_with_config(
+        r#"const LoginForm = () => <button type="submit">로그인</button>;"#,
+        &TransformerConfig {
+            key_strategy: KeyStrategy::Semantic,
+            key_prefix: true,
+            ..Default::default()
+        },
+        false,
+    );
+
+    assert!(output.contains(r#"t("button.submit", {"#), "{}", output);
+    assert!(!output.contains("LoginForm."), "{}", output);
+}
+
+#[test]
+fn transform_module_단위_명사가_붙은_개수는_count로_전달해야_함() {
+    let output = transform_code(
+        r#"const a = `${n}명 참여`;
+const b = `${months}개월 남음`;
+const c = "총 " + total + "건";"#,
+    );
+
+    assert!(output.contains(r#"t("{{count}}명 참여", {"#), "{}", output);
+    assert!(output.contains("count: n"), "{}", output);
+    assert!(output.contains(r#"t("{{count}}개월 남음", {"#), "{}", output);
+    assert!(output.contains("count: months"), "{}", output);
+    assert!(output.contains(r#"t("총 {{count}}건", {"#), "{}", output);
+    assert!(output.contains("count: total"), "{}", output);
+}
+
+#[test]
+fn transform_module_단위_명사가_없어도_개수_이름이면_count로_전달해야_함() {
+    let output = transform_code(r#"const a = `${user.name}님의 알림: ${items.length}`;"#);
+
+    assert!(
+        output.contains(r#"t("{{user_name}}님의 알림: {{count}}", {"#),
+        "{}",
+        output
+    );
+    assert!(output.contains("count: items.length"), "{}", output);
+}
+
+#[test]
+fn transform_module_단위_명사_앞의_표현식을_개수_이름보다_우선해야_함() {
+    let output = transform_code(r#"const a = `${count}회 시도, ${failed}건 실패`;"#);
+
+    assert!(
+        output.contains(r#"t("{{count2}}회 시도, {{count}}건 실패", {"#),
+        "{}",
+        output
+    );
+    assert!(output.contains("count: failed"), "{}", output);
+    assert!(output.contains("count2: count"), "{}", output);
+}
+
+#[test]
+fn transform_module_개수_표현식이_없으면_count를_만들지_않아야_함() {
+    let output = transform_code(r#"const a = `${name}님 환영합니다`;"#);
+
+    assert!(output.contains(r#"t("{{name}}님 환영합니다", {"#), "{}", output);
+    assert!(!output.contains("count"), "{}", output);
+}
+
+#[test]
+fn transform_module_그대로_둔_포맷_호출은_count로_전달하지_않아야_함() {
+    let output = transform_code(
+        r#"const a = `${n.toLocaleString("ko-KR", { maximumFractionDigits: 0 })}명 참여`;"#,
+    );
+
+    assert!(output.contains(r#"t("{{n_toLocaleString}}명 참여", {"#), "{}", output);
+    assert!(!output.contains("count"), "{}", output);
+}
+
+#[test]
+fn transform_module_설정한_단위_명사와_개수_이름을_사용해야_함() {
+    let code = r#"const a = `${size}장 남음`;
+const b = `${n}명 참여`;"#;
+    let config = TransformerConfig {
+        counter_suffixes: Some(vec!["장".to_string()]),
+        counter_names: Some(vec!["size".to_string()]),
+        ..Default::default()
+    };
+    let (output, _) = transform_with_config(code, &config, false);
+
+    assert!(output.contains(r#"t("{{count}}장 남음", {"#), "{}", output);
+    assert!(output.contains("count: size"), "{}", output);
+    assert!(output.contains(r#"t("{{n}}명 참여", {"#), "{}", output);
+}
+
+#[test]
+fn transform_module_toLocaleString과_통화_단위는_currency_포맷으로_바꿔야_함() {
+    let output = transform_code(
+        r#"const a = `${price.toLocaleString()}원 결제`;
+const b = `${amount}달러 환불`;
+const c = `${views.toLocaleString("ko-KR")} 조회`;"#,
+    );
+
+    assert!(output.contains(r#"t("{{price, currency(KRW)}} 결제", {"#), "{}", output);
+    assert!(output.contains("    price\n"), "{}", output);
+    assert!(output.contains(r#"t("{{amount, currency(USD)}} 환불", {"#), "{}", output);
+    assert!(output.contains(r#"t("{{views, number}} 조회", {"#), "{}", output);
+    assert!(!output.contains("toLocaleString"), "{}", output);
+}
+
+#[test]
+fn transform_module_통화_단위로_시작하는_단어는_currency로_바꾸지_않아야_함() {
+    let output = transform_code(
+        r#"const a = `${file}원본을 열 수 없음`;
+const b = `${name}원하는 항목`;
+const c = `${price}원을 결제`;"#,
+    );
+
+    assert!(output.contains(r#"t("{{file}}원본을 열 수 없음", {"#), "{}", output);
+    assert!(output.contains(r#"t("{{name}}원하는 항목", {"#), "{}", output);
+    assert!(output.contains(r#"t("{{price, currency(KRW)}}을 결제", {"#), "{}", output);
+}
+
+#[test]
+fn transform_module_Intl_포맷은_원래_값과_포맷_placeholder로_바꿔야_함() {
+    let output = transform_code(
+        r#"const a = `합계 ${new Intl.NumberFormat("en-US", { style: "currency", currency: "EUR" }).format(sum)}`;
+const b = `${new Intl.DateTimeFormat("ko-KR").format(createdAt)} 가입`;
+const c = `${new Intl.NumberFormat().format(size)} 바이트`;"#,
+    );
+
+    assert!(output.contains(r#"t("합계 {{sum, currency(EUR)}}", {"#), "{}", output);
+    assert!(output.contains(r#"t("{{createdAt, datetime}} 가입", {"#), "{}", output);
+    assert!(output.contains(r#"t("{{size, number}} 바이트", {"#), "{}", output);
+    assert!(!output.contains("Intl"), "{}", output);
+}
+
+#[test]
+fn transform_module_같은_Date의_getter_묶음은_datetime_포맷으로_합쳐야_함() {
+    let output = transform_code(
+        r#"const a = `${d.getMonth() + 1}월 ${d.getDate()}일 예약`;
+const b = `${date.toLocaleDateString()} 기준`;"#,
+    );
+
+    assert!(output.contains(r#"t("{{d, datetime}} 예약", {"#), "{}", output);
+    assert!(output.contains(r#"t("{{date, datetime}} 기준", {"#), "{}", output);
+    assert!(!output.contains("getMonth"), "{}", output);
+}
+
+#[test]
+fn transform_module_옮길_수_없는_포맷은_유지하고_보고해야_함() {
+    let code = r#"const a = `${d.getFullYear()}년 결산`;
+const b = `${price.toLocaleString("ko-KR", { maximumFractionDigits: 0 })} 포인트`;
+const c = `${d.toLocaleTimeString()} 도착`;"#;
+    let mut module = parse_file(code, ParseOptions::default()).unwrap();
+    let (result, _) = transform_module(&mut module, code.to_string());
+    let output = generate_code(&module).unwrap();
+
+    assert!(output.contains(r#"t("{{d_getFullYear}}년 결산", {"#), "{}", output);
+    assert!(output.contains("maximumFractionDigits"), "{}", output);
+    assert!(output.contains(r#"t("{{d_toLocaleTimeString}} 도착", {"#), "{}", output);
+
+    assert!(result.skipped.is_empty(), "{:?}", result.skipped);
+    let unsupported: Vec<_> = result
+        .warnings
+        .iter()
+        .filter(|w| w.kind == WarningKind::UnsupportedFormat)
+        .map(|w| (w.value.as_str(), w.line))
+        .collect();
+    assert_eq!(
+        unsupported,
+        vec![
+            ("d.getFullYear()", Some(1)),
+            (r#"price.toLocaleString("ko-KR", { maximumFractionDigits: 0 })"#, Some(2)),
+            ("d.toLocaleTimeString()", Some(3)),
+        ]
+    );
+}
+
+#[test]
+fn transform_module_interpolation_뒤_조사는_기본적으로_유지하고_보고해야_함() {
+    let code = r#"const a = `${name}을 삭제했습니다`;
+const b = `${item}이(가) 추가됨`;
+const c = `${n}이상 ${path}로그인`;"#;
+    let mut module = parse_file(code, ParseOptions::default()).unwrap();
+    let (result, _) = transform_module(&mut module, code.to_string());
+    let output = generate_code(&module).unwrap();
+
+    assert!(output.contains(r#"t("{{name}}을 삭제했습니다", {"#), "{}", output);
+    assert!(output.contains(r#"t("{{item}}이(가) 추가됨", {"#), "{}", output);
+
+    assert!(result.skipped.is_empty(), "{:?}", result.skipped);
+    let particles: Vec<_> = result
+        .warnings
+        .iter()
+        .filter(|w| w.kind == WarningKind::JosaParticle)
+        .map(|w| (w.value.as_str(), w.line))
+        .collect();
+    assert_eq!(particles, vec![("name을", Some(1)), ("item이(가)", Some(2))]);
+}
+
+#[test]
+fn transform_module_포맷을_붙인_값_뒤_조사는_유지하고_보고해야_함() {
+    let code = r#"const a = `${price.toLocaleString()}원이 결제됨`;
+const b = `${views.toLocaleString()}을 넘음`;"#;
+    let config = TransformerConfig {
+        josa_hints: true,
+        ..Default::default()
+    };
+    let (output, result) = transform_with_config(code, &config, false);
+
+    assert!(output.contains(r#"t("{{price, currency(KRW)}}이 결제됨", {"#), "{}", output);
+    assert!(output.contains(r#"t("{{views, number}}을 넘음", {"#), "{}", output);
+
+    let particles: Vec<_> = result
+        .warnings
+        .iter()
+        .filter(|w| w.kind == WarningKind::JosaParticle)
+        .map(|w| (w.value.as_str(), w.line))
+        .collect();
+    assert_eq!(particles, vec![("price이", Some(1)), ("views을", Some(2))]);
+}
+
+#[test]
+fn transform_module_josa_hints를_켜면_조사를_josa_포맷으로_바꿔야_함() {
+    let code = r#"const a = `${name}을 삭제했습니다`;
+const b = `${item}이(가) 추가됨`;
+const c = `${user.name}와 ${friend}과 함께`;
+const d = `${folder}(으)로 이동`;
+const e = `${dest}으로 보냄`;"#;
+    let config = TransformerConfig {
+        josa_hints: true,
+        ..Default::default()
+    };
+    let (output, result) = transform_with_config(code, &config, false);
+
+    assert!(output.contains(r#"t("{{name, josa(을/를)}} 삭제했습니다", {"#), "{}", output);
+    assert!(output.contains(r#"t("{{item, josa(이/가)}} 추가됨", {"#), "{}", output);
+    assert!(
+        output.contains(r#"t("{{user_name, josa(와/과)}} {{friend, josa(와/과)}} 함께", {"#),
+        "{}",
+        output
+    );
+    assert!(output.contains(r#"t("{{folder, josa(으로/로)}} 이동", {"#), "{}", output);
+    assert!(output.contains(r#"t("{{dest, josa(으로/로)}} 보냄", {"#), "{}", output);
+    assert!(
+        !result.warnings.iter().any(|w| w.kind == WarningKind::JosaParticle),
+        "{:?}",
+        result.warnings
+    );
+}
+
+#[test]
+fn transform_module_component_scope_only는_컴포넌트_밖_문자열을_보고해야_함() {
+    let code = r#"const TITLE = "제목";
+function format(name) {
+  return `${name}님`;
+}
+function Page() {
+  const label = "라벨";
+  return <main title="페이지">{label}</main>;
+}"#;
+    let config = TransformerConfig {
+        outside_component: OutsideComponent::Skip,
+        ..Default::default()
+    };
+    let mut module = parse_file(code, ParseOptions::default()).unwrap();
+    let (result, modified_functions) =
+        transform_module_with_config(&mut module, code.to_string(), &config, None);
+    let output = generate_code(&module).unwrap();
+
+    assert_eq!(modified_functions, vec!["Page".to_string()]);
+    assert!(output.contains(r#"const TITLE = "제목";"#), "{}", output);
+    assert!(output.contains("return `${name}님`;"), "{}", output);
+    assert!(output.contains(r#"const label = t("라벨");"#), "{}", output);
+    let unscoped: Vec<_> = result
+        .skipped
+        .iter()
+        .filter(|skipped| skipped.reason == SkipReason::NoComponentScope)
+        .map(|skipped| (skipped.value.as_str(), skipped.line))
+        .collect();
+    assert_eq!(unscoped, vec![("제목", Some(1)), ("님", Some(3))]);
+}
+
+#[test]
+fn transform_module_중첩_컴포넌트의_문자열은_가장_가까운_컴포넌트에만_기록해야_함() {
+    let code = r#"function List({ items }) {
+  const Row = ({ item }) => <li>{item.name} 항목</li>;
+  return <ul>{items.map((item) => <Row item={item} />)}</ul>;
+}
+const Card = memo(({ title }) => <section aria-label="카드">{title}</section>);"#;
+    let mut module = parse_file(code, ParseOptions::default()).unwrap();
+    let (_, modified_functions) = transform_module(&mut module, code.to_string());
+
+    assert_eq!(modified_functions, vec!["Row".to_string(), "Card".to_string()]);
+}
+
+#[test]
+fn transform_module_상수_객체의_값은_마커로_감싸고_사용처는_t로_감싸야_함() {
+    let code = r#"function StatusBadge({ status }) {
+  return <span>{STATUS_LABELS[status]}</span>;
+}
+export const STATUS_LABELS = {
+  PENDING: "대기중",
+  DONE: "완료",
+} as const;"#;
+    let marker = translation_callee(
+        &parse_file("", ParseOptions::default()).unwrap(),
+        "@/lib/i18n-markers",
+        "tKey",
+    );
+    let config = TransformerConfig {
+        outside_component: OutsideComponent::Skip,
+        deferred_constants: Some(Box::new(marker)),
+        ..Default::default()
+    };
+    let (output, result) = transform_with_config(code, &config, false);
+
+    assert!(output.contains(r#"PENDING: tKey("대기중")"#), "{}", output);
+    assert!(output.contains(r#"DONE: tKey("완료")"#), "{}", output);
+    assert!(output.contains("} as const;"), "{}", output);
+    assert!(output.contains("{t(STATUS_LABELS[status])}"), "{}", output);
+    assert_eq!(result.deferred_constants, vec!["STATUS_LABELS".to_string()]);
+    assert!(result.skipped.is_empty(), "{:?}", result.skipped);
+}
+
+#[test]
+fn transform_module_배열_상수는_순회_콜백_인자의_메시지만_감싸야_함() {
+    let code = r#"const OPTIONS = [
+  { value: "all", label: "전체" },
+  { value: "open", label: "진행중" },
+];
+function Filter() {
+  return (
+    <select>
+      {OPTIONS.map((option) => <option key={option.value} value={option.value}>{option.label}</option>)}
+      {OPTIONS.map(({ label }) => <span>{label}</span>)}
+    </select>
+  );
+}"#;
+    let marker = translation_callee(
+        &parse_file("", ParseOptions::default()).unwrap(),
+        "@/lib/i18n-markers",
+        "tKey",
+    );
+    let config = TransformerConfig {
+        outside_component: OutsideComponent::Skip,
+        deferred_constants: Some(Box::new(marker)),
+        ..Default::default()
+    };
+    let (output, _) = transform_with_config(code, &config, false);
+
+    assert!(output.contains(r#"label: tKey("전체")"#), "{}", output);
+    assert!(output.contains(r#"value: "all""#), "{}", output);
+    assert!(output.contains("{t(option.label)}"), "{}", output);
+    assert!(output.contains("value={option.value}"), "{}", output);
+    assert!(output.contains("{t(label)}"), "{}", output);
+}
+
+#[test]
+fn transform_module_메시지가_아닐_수_있는_값과_컴포넌트_밖_사용처는_감싸지_않아야_함() {
+    let code = r#"const ERRORS = { network: "네트워크 오류", code: 500 };
+function describe(key) {
+  return ERRORS[key];
+}
+function ErrorView({ name }) {
+  return <p>{ERRORS.network} {ERRORS[name]}</p>;
+}"#;
+    let marker = translation_callee(
+        &parse_file("", ParseOptions::default()).unwrap(),
+        "@/lib/i18n-markers",
+        "tKey",
+    );
+    let config = TransformerConfig {
+        outside_component: OutsideComponent::Skip,
+        deferred_constants: Some(Box::new(marker)),
+        ..Default::default()
+    };
+    let (output, _) = transform_with_config(code, &config, false);
+
+    assert!(output.contains(r#"network: tKey("네트워크 오류")"#), "{}", output);
+    assert!(output.contains("return ERRORS[key];"), "{}", output);
+    assert!(output.contains("t(ERRORS.network)"), "{}", output);
+    assert!(!output.contains("t(ERRORS[name])"), "{}", output);
+}
+
+#[test]
+fn transform_module_지연_번역은_다시_실행해도_결과가_같아야_함() {
+    let code = r#"const NAV = [{ href: "/", title: "홈" }];
+const Nav = () => <nav>{NAV.map((item) => <a href={item.href}>{item.title} 바로가기</a>)}</nav>;"#;
+    let marker = translation_callee(
+        &parse_file("", ParseOptions::default()).unwrap(),
+        "@/lib/i18n-markers",
+        "tKey",
+    );
+    let config = TransformerConfig {
+        outside_component: OutsideComponent::Skip,
+        deferred_constants: Some(Box::new(marker)),
+        ..Default::default()
+    };
+    let (output, _) = transform_with_config(code, &config, false);
+    let (rerun, result) = transform_with_config(&output, &config, false);
+
+    assert!(output.contains(r#"title: tKey("홈")"#), "{}", output);
+    assert!(output.contains(r#"t("{{item_title}} 바로가기", {"#), "{}", output);
+    assert!(output.contains("item_title: t(item.title)"), "{}", output);
+    assert_eq!(rerun, output);
+    assert!(!result.was_modified);
+    assert!(
+        !result.skipped.iter().any(|s| s.reason == SkipReason::NoComponentScope),
+        "{:?}",
+        result.skipped
+    );
+}
@@ -0,0 +1,116 @@
+#![allow(non_snake_case)]
+
+use t_wrapper_rust::parser::{parse_file, ParseOptions};
+use t_wrapper_rust::{classify_module, contains_react_component, ClassificationReason, ComponentMode};
+
+fn classify(code: &str, file_path: &str) -> (ComponentMode, ClassificationReason) {
+    let module = parse_file(code, ParseOptions::default()).unwrap();
+    let classification = classify_module(&module, file_path, "getTranslations");
+    (classification.mode, classification.reason)
+}
+
+#[test]
+fn classify_module_디렉티브가_가장_우선해야_함() {
+    let (mode, reason) = classify(
+        r#"'use client';
+export default async function Page() {
+  return <main>페이지</main>;
+}"#,
+        "app/page.tsx",
+    );
+    assert_eq!(mode, ComponentMode::Client);
+    assert_eq!(reason, ClassificationReason::UseClientDirective);
+
+    let (mode, reason) = classify(
+        r#"'use server';
+export async function save() {}"#,
+        "pages/index.tsx",
+    );
+    assert_eq!(mode, ComponentMode::Server);
+    assert_eq!(reason, ClassificationReason::UseServerDirective);
+}
+
+#[test]
+fn classify_module_기존_번역_바인딩을_따라야_함() {
+    let (mode, reason) = classify(
+        r#"async function Page() {
+  const { t } = await getTranslations();
+  return <main onClick={go}>{t("홈")}</main>;
+}"#,
+        "components/Page.tsx",
+    );
+    assert_eq!(mode, ComponentMode::Server);
+    assert_eq!(reason, ClassificationReason::ServerTranslationBinding);
+
+    let (mode, reason) = classify(
+        r#"function Page() {
+  const { t } = useTranslation();
+  return <main>{t("홈")}</main>;
+}"#,
+        "app/page.tsx",
+    );
+    assert_eq!(mode, ComponentMode::Client);
+    assert_eq!(reason, ClassificationReason::ClientTranslationBinding);
+}
+
+#[test]
+fn classify_module_훅이나_이벤트_핸들러가_있으면_클라이언트여야_함() {
+    let (mode, reason) = classify(
+        r#"export default function Counter() {
+  const [count, setCount] = useState(0);
+  return <button onClick={() => setCount(count + 1)}>증가</button>;
+}"#,
+        "app/counter/page.tsx",
+    );
+    assert_eq!(mode, ComponentMode::Client);
+    assert_eq!(reason, ClassificationReason::HookUsage("useState".to_string()));
+
+    let (mode, reason) = classify(
+        r#"const Button = () => <button onClick={save}>저장</button>;"#,
+        "app/Button.tsx",
+    );
+    assert_eq!(mode, ComponentMode::Client);
+    assert_eq!(reason, ClassificationReason::EventHandler("onClick".to_string()));
+}
+
+#[test]
+fn classify_module_async_컴포넌트는_서버여야_함() {
+    let (mode, reason) = classify(
+        r#"const Posts = async () => {
+  const posts = await fetchPosts();
+  return <ul>{posts.length}개의 글</ul>;
+};"#,
+        "pages/posts.tsx",
+    );
+    assert_eq!(mode, ComponentMode::Server);
+    assert_eq!(reason, ClassificationReason::AsyncComponent("Posts".to_string()));
+}
+
+#[test]
+fn classify_module_신호가_없으면_파일_위치로_판단해야_함() {
+    let code = r#"export default function About() {
+  return <main>소개</main>;
+}"#;
+
+    assert_eq!(
+        classify(code, "src/app/about/page.tsx"),
+        (ComponentMode::Server, ClassificationReason::AppRouter)
+    );
+    assert_eq!(
+        classify(code, "app/src/pages/about.tsx"),
+        (ComponentMode::Client, ClassificationReason::Default)
+    );
+    assert_eq!(
+        classify(code, "src\\components\\About.tsx"),
+        (ComponentMode::Client, ClassificationReason::Default)
+    );
+}
+
+#[test]
+fn contains_react_component_이름_없는_export_default도_컴포넌트로_봐야_함() {
+    let contains = |code: &str| contains_react_component(&parse_file(code, ParseOptions::default()).unwrap());
+
+    assert!(contains("export default function () { return <p>안녕</p>; }"));
+    assert!(contains("export default () => <p>안녕</p>;"));
+    assert!(!contains("export default \"안녕\";"));
+}
@@ -1,6 +1,12 @@
+#![allow(non_snake_case)]
+
 use t_wrapper_rust::wrap_translations;
 use t_wrapper_rust::ScriptConfig;
+use t_wrapper_rust::{ComponentMode, SkipReason};
+use t_wrapper_rust::parser::{parse_file, ParseOptions};
 use std::fs;
+use std::path::Path;
+use swc_ecma_ast::{Decl, ImportSpecifier, ModuleDecl, ModuleExportName, ModuleItem, Pat};
 use tempfile::TempDir;
 
 #[test]
@@ -142,3 +148,347 @@ fn e2e_serverTranslationFunction_커스텀_함수명을_사용해야_함() {
     assert!(modified.contains("import { getServerT } from"));
 }
 
+
+#[test]
+fn e2e_mode가_없으면_파일마다_client_server를_분류해야_함() {
+    let temp_dir = TempDir::new().unwrap();
+    let app_dir = temp_dir.path().join("app");
+    fs::create_dir_all(&app_dir).unwrap();
+    let server_file = app_dir.join("page.tsx");
+    let client_file = app_dir.join("Counter.tsx");
+    fs::write(
+        &server_file,
+        r#"export default function Page() {
+  return <main>페이지</main>;
+}"#,
+    )
+    .unwrap();
+    fs::write(
+        &client_file,
+        r#"export default function Counter() {
+  const [count, setCount] = useState(0);
+  return <button onClick={() => setCount(count + 1)}>증가</button>;
+}"#,
+    )
+    .unwrap();
+
+    let config = ScriptConfig {
+        source_pattern: temp_dir.path().join("**/*.tsx").to_string_lossy().to_string(),
+        framework: Some("nextjs".to_string()),
+        ..Default::default()
+    };
+
+    let result = wrap_translations(Some(config)).unwrap();
+
+    let server = fs::read_to_string(&server_file).unwrap();
+    assert!(server.contains("await getTranslations()"), "{}", server);
+    assert!(!server.contains("use client"), "{}", server);
+    let client = fs::read_to_string(&client_file).unwrap();
+    assert!(client.contains("useTranslation()"), "{}", client);
+    assert!(client.starts_with("'use client';"), "{}", client);
+
+    assert_eq!(result.classifications.len(), 2);
+    for file in &result.classifications {
+        let expected = if file.file.ends_with("page.tsx") {
+            ComponentMode::Server
+        } else {
+            ComponentMode::Client
+        };
+        assert_eq!(file.classification.mode, expected, "{}", file.file);
+    }
+}
+
+#[test]
+fn e2e_컴포넌트_밖_문자열은_t로_바꾸지_않고_보고해야_함() {
+    let temp_dir = TempDir::new().unwrap();
+    let test_file = temp_dir.path().join("Labels.tsx");
+    fs::write(
+        &test_file,
+        r#"export const LABEL = "저장";
+export function Labels() {
+  return <span>취소</span>;
+}"#,
+    )
+    .unwrap();
+
+    let config = ScriptConfig {
+        source_pattern: temp_dir.path().join("**/*.tsx").to_string_lossy().to_string(),
+        ..Default::default()
+    };
+
+    let result = wrap_translations(Some(config)).unwrap();
+
+    let modified = fs::read_to_string(&test_file).unwrap();
+    assert!(modified.contains(r#"export const LABEL = "저장";"#), "{}", modified);
+    assert!(modified.contains(r#"{t("취소")}"#), "{}", modified);
+    assert_eq!(result.skipped.len(), 1);
+    assert_eq!(result.skipped[0].skipped[0].value, "저장");
+    assert_eq!(result.skipped[0].skipped[0].reason, SkipReason::NoComponentScope);
+}
+
+fn wrap_utils_file(content: &str, config: ScriptConfig) -> (String, t_wrapper_rust::WrapResult) {
+    let temp_dir = TempDir::new().unwrap();
+    let test_file = temp_dir.path().join("messages.ts");
+    fs::write(&test_file, content).unwrap();
+
+    let config = ScriptConfig {
+        source_pattern: temp_dir.path().join("**/*.ts").to_string_lossy().to_string(),
+        ..config
+    };
+    let result = wrap_translations(Some(config)).unwrap();
+    (fs::read_to_string(&test_file).unwrap(), result)
+}
+
+#[test]
+fn e2e_컴포넌트가_없는_파일은_기본적으로_보고만_해야_함() {
+    let content = r#"export function toastMessage(name: string) {
+  return `${name}님 환영합니다`;
+}"#;
+    let (modified, result) = wrap_utils_file(content, ScriptConfig::default());
+
+    assert_eq!(modified, content);
+    assert!(result.processed_files.is_empty());
+    assert_eq!(result.skipped[0].skipped[0].reason, SkipReason::NoComponentScope);
+}
+
+#[test]
+fn e2e_global_전략은_전역_i18n_인스턴스로_변환해야_함() {
+    let config = ScriptConfig {
+        non_component_strategy: Some("global".to_string()),
+        ..Default::default()
+    };
+    let (modified, result) = wrap_utils_file(
+        r#"import axios from "axios";
+export async function save() {
+  await axios.post("/api");
+  throw new Error("저장에 실패했습니다");
+}"#,
+        config.clone(),
+    );
+
+    assert!(
+        modified.starts_with("import axios from \"axios\";\nimport i18n from \"@/lib/i18n\";\n"),
+        "{}",
+        modified
+    );
+    assert!(modified.contains(r#"new Error(i18n.t("저장에 실패했습니다"))"#), "{}", modified);
+    assert!(!modified.contains("useTranslation"), "{}", modified);
+    assert!(result.skipped.is_empty());
+
+    let (rerun, _) = wrap_utils_file(&modified, config);
+    assert_eq!(rerun, modified);
+}
+
+#[test]
+fn e2e_global_전략은_기존_default_import_이름을_재사용해야_함() {
+    let config = ScriptConfig {
+        non_component_strategy: Some("global".to_string()),
+        i18n_instance_source: Some("@/i18n".to_string()),
+        ..Default::default()
+    };
+    let (modified, _) = wrap_utils_file(
+        r#"import i18next from "@/i18n";
+export const notify = () => alert("완료");"#,
+        config,
+    );
+
+    assert!(modified.contains(r#"alert(i18next.t("완료"))"#), "{}", modified);
+    assert_eq!(modified.matches("import").count(), 1, "{}", modified);
+}
+
+#[test]
+fn e2e_lazy_전략은_마커_함수로_감싸야_함() {
+    let config = ScriptConfig {
+        non_component_strategy: Some("lazy".to_string()),
+        lazy_translation_source: Some("@/lib/i18n-markers".to_string()),
+        ..Default::default()
+    };
+    let (modified, _) = wrap_utils_file(
+        r#"export const STATUS = {
+  done: "완료",
+  pending: "대기 중",
+};"#,
+        config,
+    );
+
+    assert!(modified.starts_with("import { tKey } from \"@/lib/i18n-markers\";"), "{}", modified);
+    assert!(modified.contains(r#"done: tKey("완료")"#), "{}", modified);
+    assert!(modified.contains(r#"pending: tKey("대기 중")"#), "{}", modified);
+}
+
+/// 파일의 `source` import가 가져오는 이름을 그 모듈이 실제로 export하는지 확인
+/// 상대 경로 소스를 파일 위치 기준으로 풀어 .ts 모듈을 읽음
+fn assert_import_resolves(file: &Path, source: &str) {
+    let module = parse_file(&fs::read_to_string(file).unwrap(), ParseOptions::default()).unwrap();
+    let imported: Vec<String> = module
+        .body
+        .iter()
+        .filter_map(|item| match item {
+            ModuleItem::ModuleDecl(ModuleDecl::Import(import)) if import.src.value.to_string_lossy() == source => {
+                Some(import)
+            }
+            _ => None,
+        })
+        .flat_map(|import| &import.specifiers)
+        .filter_map(|specifier| match specifier {
+            ImportSpecifier::Named(named) => Some(match &named.imported {
+                Some(ModuleExportName::Ident(ident)) => ident.sym.to_string(),
+                Some(ModuleExportName::Str(str_lit)) => str_lit.value.to_string_lossy().to_string(),
+                None => named.local.sym.to_string(),
+            }),
+            _ => None,
+        })
+        .collect();
+    assert!(!imported.is_empty(), "no import from {}", source);
+
+    let target = file.parent().unwrap().join(format!("{}.ts", source));
+    let target = parse_file(&fs::read_to_string(&target).unwrap(), ParseOptions::default()).unwrap();
+    let exported: Vec<String> = target
+        .body
+        .iter()
+        .flat_map(|item| match item {
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export)) => match &export.decl {
+                Decl::Fn(fn_decl) => vec![fn_decl.ident.sym.to_string()],
+                Decl::Var(var_decl) => var_decl
+                    .decls
+                    .iter()
+                    .filter_map(|declarator| match &declarator.name {
+                        Pat::Ident(binding) => Some(binding.id.sym.to_string()),
+                        _ => None,
+                    })
+                    .collect(),
+                _ => Vec::new(),
+            },
+            _ => Vec::new(),
+        })
+        .collect();
+    for name in imported {
+        assert!(exported.contains(&name), "{} does not export {}", source, name);
+    }
+}
+
+#[test]
+fn e2e_lazy_전략의_마커_import는_설정한_모듈로_해석되어야_함() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::create_dir_all(temp_dir.path().join("lib")).unwrap();
+    fs::create_dir_all(temp_dir.path().join("utils")).unwrap();
+    fs::write(
+        temp_dir.path().join("lib/i18n-markers.ts"),
+        "export const tKey = <K extends string>(key: K): K => key;\n",
+    )
+    .unwrap();
+    let test_file = temp_dir.path().join("utils/messages.ts");
+    fs::write(&test_file, r#"export const SAVED = "저장했습니다";"#).unwrap();
+
+    let config = ScriptConfig {
+        source_pattern: temp_dir.path().join("utils/**/*.ts").to_string_lossy().to_string(),
+        non_component_strategy: Some("lazy".to_string()),
+        lazy_translation_source: Some("../lib/i18n-markers".to_string()),
+        ..Default::default()
+    };
+    wrap_translations(Some(config)).unwrap();
+
+    let modified = fs::read_to_string(&test_file).unwrap();
+    assert!(modified.contains(r#"SAVED = tKey("저장했습니다")"#), "{}", modified);
+    assert!(!modified.contains("i18nexus"), "{}", modified);
+    assert_import_resolves(&test_file, "../lib/i18n-markers");
+}
+
+#[test]
+fn e2e_lazy_전략은_마커_모듈이_없으면_에러를_반환해야_함() {
+    let config = ScriptConfig {
+        non_component_strategy: Some("lazy".to_string()),
+        ..Default::default()
+    };
+    assert!(wrap_translations(Some(config)).is_err());
+}
+
+#[test]
+fn e2e_컴포넌트가_있는_파일은_전략과_관계없이_컴포넌트_밖_문자열을_보고해야_함() {
+    let temp_dir = TempDir::new().unwrap();
+    let test_file = temp_dir.path().join("Page.tsx");
+    fs::write(
+        &test_file,
+        r#"const TITLE = "제목";
+export function Page() {
+  return <h1>{TITLE} 페이지</h1>;
+}"#,
+    )
+    .unwrap();
+
+    let config = ScriptConfig {
+        source_pattern: temp_dir.path().join("**/*.tsx").to_string_lossy().to_string(),
+        non_component_strategy: Some("global".to_string()),
+        ..Default::default()
+    };
+    let result = wrap_translations(Some(config)).unwrap();
+
+    let modified = fs::read_to_string(&test_file).unwrap();
+    assert!(modified.contains(r#"const TITLE = "제목";"#), "{}", modified);
+    assert!(!modified.contains("i18n.t("), "{}", modified);
+    assert!(!modified.contains("@/lib/i18n"), "{}", modified);
+    assert_eq!(result.skipped[0].skipped[0].value, "제목");
+}
+
+#[test]
+fn e2e_알_수_없는_non_component_strategy는_에러를_반환해야_함() {
+    let config = ScriptConfig {
+        non_component_strategy: Some("hook".to_string()),
+        ..Default::default()
+    };
+    assert!(wrap_translations(Some(config)).is_err());
+}
+
+#[test]
+fn e2e_defer_constants는_상수를_마커로_감싸고_사용처에서_번역해야_함() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::create_dir_all(temp_dir.path().join("lib")).unwrap();
+    fs::create_dir_all(temp_dir.path().join("components")).unwrap();
+    fs::write(
+        temp_dir.path().join("lib/i18n-markers.ts"),
+        "export function tKey<K extends string>(key: K): K {\n  return key;\n}\n",
+    )
+    .unwrap();
+    let test_file = temp_dir.path().join("components/StatusBadge.tsx");
+    fs::write(
+        &test_file,
+        r#"const STATUS_LABELS = {
+  PENDING: "대기중",
+  DONE: "완료",
+};
+export function StatusBadge({ status }) {
+  return <span>{STATUS_LABELS[status]}</span>;
+}"#,
+    )
+    .unwrap();
+
+    let config = ScriptConfig {
+        source_pattern: temp_dir.path().join("components/**/*.tsx").to_string_lossy().to_string(),
+        mode: Some("client".to_string()),
+        defer_constants: true,
+        lazy_translation_source: Some("../lib/i18n-markers".to_string()),
+        ..Default::default()
+    };
+    let result = wrap_translations(Some(config.clone())).unwrap();
+
+    let modified = fs::read_to_string(&test_file).unwrap();
+    assert!(modified.contains(r#"PENDING: tKey("대기중")"#), "{}", modified);
+    assert!(modified.contains("{t(STATUS_LABELS[status])}"), "{}", modified);
+    assert!(modified.contains("const { t } = useTranslation();"), "{}", modified);
+    assert!(modified.contains(r#"import { useTranslation } from "i18nexus";"#), "{}", modified);
+    assert!(modified.contains(r#"import { tKey } from "../lib/i18n-markers";"#), "{}", modified);
+    assert_import_resolves(&test_file, "../lib/i18n-markers");
+    assert!(result.skipped.is_empty(), "{:?}", result.skipped);
+
+    wrap_translations(Some(config)).unwrap();
+    assert_eq!(fs::read_to_string(&test_file).unwrap(), modified);
+}
+
+#[test]
+fn e2e_defer_constants는_마커_모듈이_없으면_에러를_반환해야_함() {
+    let config = ScriptConfig {
+        defer_constants: true,
+        ..Default::default()
+    };
+    assert!(wrap_translations(Some(config)).is_err());
+}
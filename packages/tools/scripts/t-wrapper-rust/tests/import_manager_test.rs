@@ -1,4 +1,9 @@
-use t_wrapper_rust::{create_use_translation_hook, add_import_if_needed};
+#![allow(non_snake_case)]
+
+use t_wrapper_rust::{
+    add_import_if_needed, create_use_translation_hook, ensure_default_import, find_default_import, create_use_translation_hook_with_options,
+    ensure_named_import, ensure_use_client_directive, find_imported_binding, ImportedBinding,
+};
 use t_wrapper_rust::parser::{parse_file, generate_code, ParseOptions};
 
 #[test]
@@ -31,4 +36,154 @@ fn create_use_translation_hook_훅_생성_테스트() {
     assert!(code.contains("const"));
     assert!(code.contains("t"));
     assert!(code.contains("useTranslation"));
+    assert!(code.contains("const { t } = useTranslation();"), "{}", code);
+}
+
+#[test]
+fn ensure_named_import_기존_import에_specifier를_병합해야_함() {
+    let code = r#"import { useTranslation } from "i18nexus";
+function Component() {}"#;
+
+    let mut ast = parse_file(code, ParseOptions::default()).unwrap();
+    assert!(ensure_named_import(&mut ast, "i18nexus", "Trans"));
+    assert!(!ensure_named_import(&mut ast, "i18nexus", "Trans"));
+
+    let output = generate_code(&ast).unwrap();
+    assert!(output.contains(r#"import { useTranslation, Trans } from "i18nexus";"#), "{}", output);
+}
+
+#[test]
+fn create_use_translation_hook_with_options_keyPrefix를_넘겨야_함() {
+    let mut module = parse_file("", ParseOptions::default()).unwrap();
+    module.body.push(swc_ecma_ast::ModuleItem::Stmt(create_use_translation_hook_with_options(
+        None,
+        Some("LoginForm"),
+    )));
+    module.body.push(swc_ecma_ast::ModuleItem::Stmt(create_use_translation_hook_with_options(
+        Some("common"),
+        Some("LoginForm"),
+    )));
+    let code = generate_code(&module).unwrap();
+
+    assert!(code.contains(r#"useTranslation(undefined, {"#), "{}", code);
+    assert!(code.contains(r#"useTranslation("common", {"#), "{}", code);
+    assert!(code.contains(r#"keyPrefix: "LoginForm""#), "{}", code);
+}
+
+#[test]
+fn ensure_use_client_directive_import보다_위_shebang_아래에_추가해야_함() {
+    let code = r#"#!/usr/bin/env node
+import { useTranslation } from "i18nexus";
+function Component() {}"#;
+
+    let mut ast = parse_file(code, ParseOptions::default()).unwrap();
+    assert!(ensure_use_client_directive(&mut ast));
+
+    let output = generate_code(&ast).unwrap();
+    assert!(
+        output.starts_with("#!/usr/bin/env node\n'use client';\nimport { useTranslation }"),
+        "{}",
+        output
+    );
+}
+
+#[test]
+fn ensure_use_client_directive_이미_있거나_use_server_파일이면_추가하지_않아야_함() {
+    for code in [
+        r#"'use strict';
+"use client";
+function Component() {}"#,
+        r#"'use server';
+export async function action() {}"#,
+    ] {
+        let mut ast = parse_file(code, ParseOptions::default()).unwrap();
+        assert!(!ensure_use_client_directive(&mut ast), "{}", code);
+
+        let output = generate_code(&ast).unwrap();
+        assert_eq!(output.matches("use client").count(), code.matches("use client").count());
+    }
+}
+
+#[test]
+fn add_import_if_needed_별칭이나_네임스페이스로_가져오면_그대로_둬야_함() {
+    for (code, expected) in [
+        (
+            r#"import { useTranslation as useT } from "i18nexus";"#,
+            ImportedBinding::Named("useT".to_string()),
+        ),
+        (
+            r#"import * as i18n from "i18nexus";"#,
+            ImportedBinding::Namespace("i18n".to_string()),
+        ),
+    ] {
+        let mut ast = parse_file(code, ParseOptions::default()).unwrap();
+        assert!(!add_import_if_needed(&mut ast, "i18nexus"), "{}", code);
+        assert_eq!(find_imported_binding(&ast, "i18nexus", "useTranslation"), Some(expected));
+        assert_eq!(generate_code(&ast).unwrap().matches("import").count(), 1);
+    }
+}
+
+#[test]
+fn add_import_if_needed_import_type은_무시하고_값_import를_추가해야_함() {
+    let code = r#"import type { useTranslation } from "i18nexus";
+import { type TFunction } from "i18nexus";
+function Component() {}"#;
+
+    let mut ast = parse_file(code, ParseOptions::default()).unwrap();
+    assert!(add_import_if_needed(&mut ast, "i18nexus"));
+
+    let output = generate_code(&ast).unwrap();
+    assert!(output.contains(r#"import type { useTranslation } from "i18nexus";"#), "{}", output);
+    assert!(
+        output.contains(r#"import { type TFunction, useTranslation } from "i18nexus";"#),
+        "{}",
+        output
+    );
+}
+
+#[test]
+fn add_import_if_needed_디렉티브와_마지막_import_뒤에_추가하고_다시_실행해도_같아야_함() {
+    let code = r#"'use client';
+import React from "react";
+import { Button } from "./Button";
+function Component() {}"#;
+
+    let mut ast = parse_file(code, ParseOptions::default()).unwrap();
+    assert!(add_import_if_needed(&mut ast, "i18nexus"));
+    let output = generate_code(&ast).unwrap();
+    assert!(
+        output.contains(
+            "import { Button } from \"./Button\";\nimport { useTranslation } from \"i18nexus\";\nfunction Component"
+        ),
+        "{}",
+        output
+    );
+
+    let mut ast = parse_file(&output, ParseOptions::default()).unwrap();
+    assert!(!add_import_if_needed(&mut ast, "i18nexus"));
+    assert_eq!(generate_code(&ast).unwrap(), output);
+
+    let mut ast = parse_file("'use client';\nfunction Component() {}", ParseOptions::default()).unwrap();
+    assert!(add_import_if_needed(&mut ast, "i18nexus"));
+    let output = generate_code(&ast).unwrap();
+    assert!(output.starts_with("'use client';\nimport { useTranslation }"), "{}", output);
+}
+
+#[test]
+fn ensure_default_import_기존_default_import가_없을_때만_추가해야_함() {
+    let code = r#"import type I18n from "@/lib/i18n";
+import { format } from "./format";"#;
+
+    let mut ast = parse_file(code, ParseOptions::default()).unwrap();
+    assert_eq!(find_default_import(&ast, "@/lib/i18n"), None);
+    assert!(ensure_default_import(&mut ast, "@/lib/i18n", "i18n"));
+    assert!(!ensure_default_import(&mut ast, "@/lib/i18n", "i18n"));
+    assert_eq!(find_default_import(&ast, "@/lib/i18n"), Some("i18n".to_string()));
+
+    let output = generate_code(&ast).unwrap();
+    assert!(
+        output.ends_with("import { format } from \"./format\";\nimport i18n from \"@/lib/i18n\";\n"),
+        "{}",
+        output
+    );
 }
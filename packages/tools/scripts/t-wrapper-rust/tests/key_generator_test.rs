@@ -0,0 +1,90 @@
+use t_wrapper_rust::{generate_key, hash_key, slugify, KeyContext, KeyRegistry, KeyStrategy};
+
+#[test]
+fn key_strategy_parse_설정_문자열을_전략으로_변환해야_함() {
+    assert_eq!(KeyStrategy::parse(None).unwrap(), KeyStrategy::Source);
+    assert_eq!(KeyStrategy::parse(Some("source")).unwrap(), KeyStrategy::Source);
+    assert_eq!(KeyStrategy::parse(Some("hash")).unwrap(), KeyStrategy::Hash);
+    assert_eq!(KeyStrategy::parse(Some("slug")).unwrap(), KeyStrategy::Slug);
+    assert_eq!(KeyStrategy::parse(Some("semantic")).unwrap(), KeyStrategy::Semantic);
+    assert!(KeyStrategy::parse(Some("random")).is_err());
+}
+
+#[test]
+fn hash_key_같은_텍스트는_항상_같은_키를_만들어야_함() {
+    assert_eq!(hash_key("안녕하세요"), hash_key("안녕하세요"));
+    assert_ne!(hash_key("안녕하세요"), hash_key("안녕하세요!"));
+    assert_eq!(hash_key("안녕하세요").len(), 12);
+    assert!(hash_key("안녕하세요").chars().all(|c| c.is_ascii_hexdigit()));
+}
+
+#[test]
+fn slugify_한글은_로마자로_나머지는_밑줄로_구분해야_함() {
+    assert_eq!(slugify("안녕하세요"), "annyeonghaseyo");
+    assert_eq!(slugify("저장 완료!"), "jeojang_wanryo");
+    assert_eq!(slugify("Save changes"), "save_changes");
+    assert_eq!(slugify("{{count}}개 항목"), "count_gae_hangmok");
+    assert_eq!(slugify("こんにちは"), "");
+}
+
+#[test]
+fn generate_key_긴_텍스트와_슬러그로_만들_수_없는_텍스트는_해시로_대체해야_함() {
+    let context = KeyContext::default();
+    let long_text = "가".repeat(81);
+
+    assert_eq!(generate_key(KeyStrategy::Source, "안녕", &context), "안녕");
+    assert_eq!(generate_key(KeyStrategy::Source, &long_text, &context), hash_key(&long_text));
+    assert_eq!(generate_key(KeyStrategy::Slug, &long_text, &context), hash_key(&long_text));
+    assert_eq!(generate_key(KeyStrategy::Slug, "こんにちは", &context), hash_key("こんにちは"));
+    assert_eq!(generate_key(KeyStrategy::Semantic, "안녕", &context), hash_key("안녕"));
+}
+
+#[test]
+fn generate_key_semantic은_컴포넌트_엘리먼트_속성으로_키를_만들어야_함() {
+    let context = KeyContext {
+        component: Some("LoginForm".to_string()),
+        element: Some("input".to_string()),
+        attribute: Some("placeholder".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(
+        generate_key(KeyStrategy::Semantic, "이메일", &context),
+        "LoginForm.input.placeholder"
+    );
+
+    let context = KeyContext {
+        component: Some("ProductCard".to_string()),
+        element: Some("img".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(generate_key(KeyStrategy::Semantic, "상품", &context), "ProductCard.img");
+}
+
+#[test]
+fn generate_key_semantic은_변수명과_엘리먼트_구분자를_포함해야_함() {
+    let context = KeyContext {
+        component: Some("SettingsPage".to_string()),
+        binding: Some("title".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(generate_key(KeyStrategy::Semantic, "설정", &context), "SettingsPage.title");
+
+    let context = KeyContext {
+        component: Some("LoginForm".to_string()),
+        element: Some("button".to_string()),
+        qualifier: Some("submit".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(generate_key(KeyStrategy::Semantic, "로그인", &context), "LoginForm.button.submit");
+}
+
+#[test]
+fn key_registry_충돌하는_키에는_접미사를_붙이고_같은_원문은_재사용해야_함() {
+    let mut registry = KeyRegistry::default();
+
+    assert_eq!(registry.resolve("Card.p".to_string(), "첫째"), "Card.p");
+    assert_eq!(registry.resolve("Card.p".to_string(), "둘째"), "Card.p_2");
+    assert_eq!(registry.resolve("Card.p".to_string(), "셋째"), "Card.p_3");
+    assert_eq!(registry.resolve("Card.p".to_string(), "둘째"), "Card.p_2");
+    assert_eq!(registry.resolve("Card.p".to_string(), "첫째"), "Card.p");
+}
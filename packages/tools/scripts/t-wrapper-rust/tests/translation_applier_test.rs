@@ -0,0 +1,495 @@
+#![allow(non_snake_case)]
+
+use t_wrapper_rust::parser::{generate_code, parse_file, ParseOptions};
+use t_wrapper_rust::transform_module;
+use t_wrapper_rust::{apply_translations_to_ast, ApplierConfig};
+
+fn client_config() -> ApplierConfig {
+    ApplierConfig {
+        mode: None,
+        framework: None,
+        server_translation_function: None,
+        translation_import_source: "i18nexus".to_string(),
+        key_prefix: false,
+    }
+}
+
+fn wrap_code(code: &str, config: &ApplierConfig) -> String {
+    let mut module = parse_file(code, ParseOptions::default()).unwrap();
+    let (result, modified_functions) = transform_module(&mut module, code.to_string());
+    apply_translations_to_ast(&mut module, &modified_functions, &result.translation_bindings, config);
+    generate_code(&module).unwrap()
+}
+
+#[test]
+fn apply_translations_to_ast_function_선언_컴포넌트에_훅을_첫_구문으로_삽입해야_함() {
+    let output = wrap_code(
+        r#"function Greeting() {
+  const name = getName();
+  return <div>안녕하세요</div>;
+}"#,
+        &client_config(),
+    );
+
+    assert!(
+        output.contains("function Greeting() {\n    const { t } = useTranslation();\n    const name"),
+        "{}",
+        output
+    );
+}
+
+#[test]
+fn apply_translations_to_ast_화살표_함수와_export_default_컴포넌트에도_삽입해야_함() {
+    let output = wrap_code(
+        r#"export const Title = () => <h1>제목</h1>;
+const Body = function () {
+  return <p>본문</p>;
+};
+export default function Page() {
+  return <main>페이지</main>;
+}"#,
+        &client_config(),
+    );
+
+    assert_eq!(output.matches("const { t } = useTranslation();").count(), 3, "{}", output);
+    assert!(output.contains("return <h1>{t(\"제목\")}</h1>;"), "{}", output);
+}
+
+#[test]
+fn apply_translations_to_ast_이름_없는_export_default_컴포넌트에도_삽입해야_함() {
+    let output = wrap_code(
+        r#"export default function () {
+  return <p>안녕</p>;
+}"#,
+        &client_config(),
+    );
+    assert!(
+        output.contains("export default function() {\n    const { t } = useTranslation();"),
+        "{}",
+        output
+    );
+
+    let output = wrap_code("export default () => <p>안녕</p>;", &client_config());
+    assert!(output.contains("const { t } = useTranslation();"), "{}", output);
+    assert!(output.contains(r#"return <p>{t("안녕")}</p>;"#), "{}", output);
+}
+
+#[test]
+fn apply_translations_to_ast_변환되지_않은_컴포넌트와_번역_훅을_이미_바인딩한_컴포넌트는_건너뛰어야_함() {
+    let output = wrap_code(
+        r#"import { useTranslation as useT } from "i18nexus";
+function Plain() {
+  return <div>hello</div>;
+}
+function WithHook() {
+  const { t } = useT();
+  return <div>안녕하세요</div>;
+}
+async function WithServer() {
+  const { t: tr } = await getTranslations();
+  return <div>{tr("제목")}본문</div>;
+}"#,
+        &client_config(),
+    );
+
+    assert_eq!(output.matches("useT()").count(), 1, "{}", output);
+    assert!(!output.contains("useTranslation()"), "{}", output);
+    assert!(output.contains(r#"{t("안녕하세요")}"#), "{}", output);
+}
+
+#[test]
+fn apply_translations_to_ast_번역_훅이_아닌_t_바인딩은_이름_충돌로_보고_tr로_삽입해야_함() {
+    let output = wrap_code(
+        r#"function Chart({ t }) {
+  return <div title="차트">{t}</div>;
+}
+function Clock() {
+  const { t } = useClock();
+  return <span>{t}초</span>;
+}
+const Duration = ({ value }) => {
+  const { t: time } = parse(value);
+  return <span>{time}분</span>;
+};"#,
+        &client_config(),
+    );
+
+    assert!(
+        output.contains("function Chart({ t }) {\n    const { t: tr } = useTranslation();"),
+        "{}",
+        output
+    );
+    assert!(output.contains(r#"title={tr("차트")}"#), "{}", output);
+    assert!(
+        output.contains("const { t: tr } = useTranslation();\n    const { t } = useClock();"),
+        "{}",
+        output
+    );
+    assert!(
+        output.contains("const Duration = ({ value })=>{\n    const { t } = useTranslation();"),
+        "{}",
+        output
+    );
+    assert_eq!(output.matches("useTranslation()").count(), 3, "{}", output);
+}
+
+#[test]
+fn apply_translations_to_ast_props의_t와_충돌해_바꾼_이름은_다시_실행해도_유지해야_함() {
+    let once = wrap_code(
+        r#"const Chart = ({ t }) => <div title="차트">{t}</div>;"#,
+        &client_config(),
+    );
+    let twice = wrap_code(&once, &client_config());
+
+    assert!(once.contains("const { t: tr } = useTranslation();"), "{}", once);
+    assert_eq!(once, twice);
+}
+
+#[test]
+fn apply_translations_to_ast_keyPrefix를_켜면_컴포넌트_이름을_넘겨야_함() {
+    let config = ApplierConfig {
+        key_prefix: true,
+        ..client_config()
+    };
+    let output = wrap_code(
+        r#"function LoginForm() {
+  return <button>로그인</button>;
+}"#,
+        &config,
+    );
+
+    assert!(
+        output.contains(r#"useTranslation(undefined, {
+        keyPrefix: "LoginForm"
+    })"#),
+        "{}",
+        output
+    );
+}
+
+fn server_config() -> ApplierConfig {
+    ApplierConfig {
+        mode: Some("server".to_string()),
+        ..client_config()
+    }
+}
+
+#[test]
+fn apply_translations_to_ast_server_모드는_컴포넌트를_async로_바꾸고_await_바인딩을_삽입해야_함() {
+    let output = wrap_code(
+        r#"export default function Page() {
+  return <main>페이지</main>;
+}
+const Header = () => <h1>제목</h1>;"#,
+        &server_config(),
+    );
+
+    assert!(
+        output.contains("export default async function Page() {\n    const { t } = await getTranslations();"),
+        "{}",
+        output
+    );
+    assert!(
+        output.contains("const Header = async ()=>{\n    const { t } = await getTranslations();"),
+        "{}",
+        output
+    );
+    assert!(output.contains(r#"import { getTranslations } from "i18nexus/server";"#), "{}", output);
+    assert!(!output.contains("useTranslation"), "{}", output);
+}
+
+#[test]
+fn apply_translations_to_ast_server_모드에서도_커스텀_훅은_async로_바꾸지_않고_useTranslation을_써야_함() {
+    let output = wrap_code(
+        r#"function useLabel() {
+  return "라벨";
+}
+async function Page() {
+  return <main>페이지</main>;
+}"#,
+        &server_config(),
+    );
+
+    assert!(
+        output.contains("function useLabel() {\n    const { t } = useTranslation();"),
+        "{}",
+        output
+    );
+    assert!(!output.contains("async function useLabel"), "{}", output);
+    assert!(output.contains("const { t } = await getTranslations();"), "{}", output);
+    assert!(output.contains(r#"import { useTranslation } from "i18nexus";"#), "{}", output);
+    assert!(output.contains(r#"import { getTranslations } from "i18nexus/server";"#), "{}", output);
+}
+
+#[test]
+fn apply_translations_to_ast_server_모드는_설정한_함수명을_사용해야_함() {
+    let config = ApplierConfig {
+        server_translation_function: Some("getServerT".to_string()),
+        ..server_config()
+    };
+    let output = wrap_code(
+        r#"function Page() {
+  return <main>페이지</main>;
+}"#,
+        &config,
+    );
+
+    assert!(output.contains("const { t } = await getServerT();"), "{}", output);
+    assert!(output.contains(r#"import { getServerT } from "i18nexus/server";"#), "{}", output);
+}
+
+#[test]
+fn apply_translations_to_ast_server_모드에서_이미_await로_t를_바인딩한_컴포넌트는_건너뛰어야_함() {
+    let output = wrap_code(
+        r#"async function Page() {
+  const { t } = await getTranslations();
+  return <main>{t("홈")}페이지</main>;
+}"#,
+        &server_config(),
+    );
+
+    assert_eq!(output.matches("await getTranslations()").count(), 1, "{}", output);
+    assert!(output.contains("async function Page()"), "{}", output);
+    assert!(!output.contains("import"), "{}", output);
+}
+
+#[test]
+fn apply_translations_to_ast_nextjs_client_모드에서만_use_client를_추가해야_함() {
+    let code = r#"import { Button } from "./Button";
+function Page() {
+  return <Button>저장</Button>;
+}"#;
+    let nextjs_client = ApplierConfig {
+        mode: Some("client".to_string()),
+        framework: Some("nextjs".to_string()),
+        ..client_config()
+    };
+    let react_client = ApplierConfig {
+        framework: Some("react".to_string()),
+        ..nextjs_client.clone()
+    };
+
+    let output = wrap_code(code, &nextjs_client);
+    assert!(output.starts_with("'use client';\n"), "{}", output);
+    assert_eq!(output.matches("use client").count(), 1, "{}", output);
+
+    let output = wrap_code(code, &react_client);
+    assert!(!output.contains("use client"), "{}", output);
+}
+
+#[test]
+fn apply_translations_to_ast_기존_import의_별칭과_네임스페이스를_재사용해야_함() {
+    let output = wrap_code(
+        r#"import { useTranslation as useT } from "i18nexus";
+function Page() {
+  return <main>페이지</main>;
+}"#,
+        &client_config(),
+    );
+    assert!(output.contains("const { t } = useT();"), "{}", output);
+    assert_eq!(output.matches("import").count(), 1, "{}", output);
+
+    let output = wrap_code(
+        r#"import * as i18n from "i18nexus";
+function Page() {
+  return <main>페이지</main>;
+}"#,
+        &client_config(),
+    );
+    assert!(output.contains("const { t } = i18n.useTranslation();"), "{}", output);
+    assert_eq!(output.matches("import").count(), 1, "{}", output);
+}
+
+#[test]
+fn apply_translations_to_ast_다시_실행해도_결과가_같아야_함() {
+    let once = wrap_code(
+        r#"import { Button } from "./Button";
+function Page() {
+  return <Button>저장</Button>;
+}"#,
+        &client_config(),
+    );
+    let twice = wrap_code(&once, &client_config());
+
+    assert_eq!(once, twice);
+    assert_eq!(once.matches("useTranslation").count(), 2, "{}", once);
+}
+
+#[test]
+fn apply_translations_to_ast_t가_다른_바인딩이면_tr로_바꿔_훅과_호출에_써야_함() {
+    let output = wrap_code(
+        r#"import t from "lodash/template";
+function Timeline({ items }) {
+  const render = t("<%= name %>");
+  return (
+    <ul title="타임라인">
+      {items.map((t) => <li key={t.id}>{render(t)}</li>)}
+    </ul>
+  );
+}"#,
+        &client_config(),
+    );
+
+    assert!(output.contains("const { t: tr } = useTranslation();"), "{}", output);
+    assert!(output.contains(r#"title={tr("타임라인")}"#), "{}", output);
+    assert!(output.contains(r#"const render = t("<%= name %>");"#), "{}", output);
+    assert!(!output.contains("const { t } ="), "{}", output);
+}
+
+#[test]
+fn apply_translations_to_ast_프로퍼티_키와_타입의_t는_이름_충돌로_보지_않아야_함() {
+    let output = wrap_code(
+        r#"function Chart({ padding }) {
+  const margin: { t: number } = { t: padding, b: 0 };
+  return <Plot margin={margin} title="차트" />;
+}"#,
+        &client_config(),
+    );
+
+    assert!(output.contains("const { t } = useTranslation();"), "{}", output);
+    assert!(output.contains(r#"title={t("차트")}"#), "{}", output);
+}
+
+#[test]
+fn apply_translations_to_ast_축약_프로퍼티의_t는_참조라서_이름을_바꿔야_함() {
+    let output = wrap_code(
+        r#"import t from "./time";
+function Chart() {
+  return <Plot layout={{ t }} title="차트" />;
+}"#,
+        &client_config(),
+    );
+
+    assert!(output.contains("const { t: tr } = useTranslation();"), "{}", output);
+    assert!(output.contains(r#"title={tr("차트")}"#), "{}", output);
+}
+
+#[test]
+fn apply_translations_to_ast_JSX_멤버_태그의_t는_참조라서_이름을_바꿔야_함() {
+    let output = wrap_code(
+        r#"import * as t from "./tooltip";
+function Help() {
+  return <t.Root title="도움말" />;
+}"#,
+        &client_config(),
+    );
+
+    assert!(output.contains("const { t: tr } = useTranslation();"), "{}", output);
+    assert!(output.contains(r#"<t.Root title={tr("도움말")}"#), "{}", output);
+}
+
+#[test]
+fn apply_translations_to_ast_기본값_매개변수의_t는_참조라서_이름을_바꿔야_함() {
+    let output = wrap_code(
+        r#"import t from "./time";
+function Clock({ at = t() }) {
+  return <p title="시계">{at}</p>;
+}"#,
+        &client_config(),
+    );
+
+    assert!(output.contains("const { t: tr } = useTranslation();"), "{}", output);
+    assert!(output.contains(r#"title={tr("시계")}"#), "{}", output);
+}
+
+#[test]
+fn apply_translations_to_ast_컴포넌트_밖의_export_t는_이름_충돌로_보지_않아야_함() {
+    let output = wrap_code(
+        r#"import t from "./time";
+export { t };
+function Greeting() {
+  return <p>안녕하세요</p>;
+}"#,
+        &client_config(),
+    );
+
+    assert!(output.contains("export { t };"), "{}", output);
+    assert!(output.contains("const { t } = useTranslation();"), "{}", output);
+    assert!(output.contains(r#"t("안녕하세요")"#), "{}", output);
+}
+
+#[test]
+fn apply_translations_to_ast_t와_tr이_모두_쓰이면_t2를_써야_함() {
+    let output = wrap_code(
+        r#"const Clock = () => {
+  const t = Date.now();
+  const tr = t % 1000;
+  return <span>{tr}밀리초</span>;
+};"#,
+        &client_config(),
+    );
+
+    assert!(output.contains("const { t: t2 } = useTranslation();"), "{}", output);
+    assert!(output.contains(r#"t2("{{tr}}밀리초", {"#), "{}", output);
+}
+
+#[test]
+fn apply_translations_to_ast_이름을_바꾼_바인딩은_다시_실행해도_유지해야_함() {
+    let once = wrap_code(
+        r#"function Schedule() {
+  for (const t of [1, 2]) {
+    console.log(t);
+  }
+  return <p>일정</p>;
+}"#,
+        &client_config(),
+    );
+    let twice = wrap_code(&once, &client_config());
+
+    assert!(once.contains("const { t: tr } = useTranslation();"), "{}", once);
+    assert!(once.contains(r#"tr("일정")"#), "{}", once);
+    assert_eq!(once, twice);
+}
+
+#[test]
+fn apply_translations_to_ast_콜백과_핸들러의_문자열은_컴포넌트_맨_위_훅_하나를_써야_함() {
+    let output = wrap_code(
+        r#"function TodoList({ items, loading }) {
+  if (loading) {
+    return <p>불러오는 중</p>;
+  }
+  const handleClick = () => alert("삭제했습니다");
+  function renderEmpty() {
+    return <p>비어 있음</p>;
+  }
+  return <ul onClick={handleClick}>{items.map((item) => <li title="항목">{item}</li>)}</ul>;
+}"#,
+        &client_config(),
+    );
+
+    assert_eq!(output.matches("useTranslation()").count(), 1, "{}", output);
+    assert!(
+        output.contains("function TodoList({ items, loading }) {\n    const { t } = useTranslation();\n    if (loading)"),
+        "{}",
+        output
+    );
+    assert!(output.contains(r#"alert(t("삭제했습니다"))"#), "{}", output);
+}
+
+#[test]
+fn apply_translations_to_ast_중첩_컴포넌트와_memo_forwardRef에도_삽입해야_함() {
+    let output = wrap_code(
+        r#"function List({ items }) {
+  const Row = ({ item }) => <li>{item.name} 항목</li>;
+  return <ul>{items.map((item) => <Row item={item} />)}</ul>;
+}
+const Card = memo(({ title }) => <section aria-label="카드">{title}</section>);
+export const Input = React.forwardRef(function (props, ref) {
+  return <input ref={ref} placeholder="입력" />;
+});"#,
+        &client_config(),
+    );
+
+    assert!(
+        output.contains("function List({ items }) {\n    const Row = ({ item })=>{\n        const { t } = useTranslation();"),
+        "{}",
+        output
+    );
+    assert!(output.contains("const Card = memo(({ title })=>{\n    const { t } = useTranslation();"), "{}", output);
+    assert!(
+        output.contains("React.forwardRef(function(props, ref) {\n    const { t } = useTranslation();"),
+        "{}",
+        output
+    );
+    assert_eq!(output.matches("= useTranslation()").count(), 3, "{}", output);
+}
@@ -5,6 +5,9 @@ use t_wrapper_rust::is_server_component;
 fn test_is_server_component_integration() {
     let code = "const { t } = await getServerTranslation();";
     assert!(is_server_component(code));
+
+    let code = "const { t } = await getTranslations();";
+    assert!(is_server_component(code));
     
     let code = "const { t } = useTranslation();";
     assert!(!is_server_component(code));
@@ -1,4 +1,4 @@
-/**
+/*!
  * Wtf8Atom 변환 통합 테스트
  * ast_transformers에서 실제로 사용되는 변환 로직이 올바르게 작동하는지 확인
  */